@@ -208,6 +208,265 @@ impl<const N: usize, T> Array<N, T> {
 		(buf, len)
 	}
 
+	/// construct a full array from an iterator that yields exactly `N` elements.
+	///
+	/// if the iterator yields fewer, returns `Err(count)` with the number of
+	/// elements it yielded. if it yields more, returns `Err(N + 1)`; the
+	/// iterator is not consumed any further than that first surplus element.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array = Array::<4, _>::from_iter_exact([1, 2, 3, 4]);
+	/// assert_eq!(array, Ok(Array::from_parts([1, 2, 3, 4])));
+	///
+	/// // too few
+	/// assert_eq!(Array::<4, _>::from_iter_exact([1, 2, 3]), Err(3));
+	///
+	/// // too many
+	/// assert_eq!(Array::<4, _>::from_iter_exact([1, 2, 3, 4, 5, 6]), Err(5));
+	/// ```
+	pub fn from_iter_exact<I: IntoIterator<Item = T>>(iter: I) -> Result<Array<N, T>, usize> {
+		let mut iter = iter.into_iter();
+		let mut array = Self::new();
+
+		for _ in 0..N {
+			match iter.next() {
+				Some(element) => unsafe {
+					// safety: at most `N` elements are pushed
+					array.push_unchecked(element);
+				},
+				None => return Err(array.len()),
+			}
+		}
+
+		if iter.next().is_some() {
+			return Err(N + 1);
+		}
+
+		Ok(array)
+	}
+
+	/// construct a full array, calling `f` exactly `N` times to produce
+	/// each element in order.
+	///
+	/// if `f` panics, the elements already produced are dropped.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let mut n = 0;
+	/// let array = Array::<4, _>::full_with(|| {
+	///     n += 1;
+	///     n * n
+	/// });
+	///
+	/// assert_eq!(array, [1, 4, 9, 16]);
+	/// assert_eq!(array.len(), array.capacity());
+	/// ```
+	pub fn full_with<F: FnMut() -> T>(mut f: F) -> Array<N, T> {
+		let mut array = Self::new();
+
+		for _ in 0..N {
+			unsafe {
+				// safety: exactly `N` elements are pushed
+				array.push_unchecked(f());
+			}
+		}
+
+		array
+	}
+
+	/// construct an array of `len` elements, where each element is `f(index)`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array = Array::<8, _>::from_fn(4, |i| i * 2);
+	/// assert_eq!(array, [0, 2, 4, 6]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `len` is greater than the array capacity.
+	///
+	/// ```should_panic
+	/// # use nyarray::array::Array;
+	/// let array = Array::<2, _>::from_fn(3, |i| i); // panics
+	/// ```
+	#[inline]
+	pub fn from_fn<F: FnMut(usize) -> T>(len: usize, mut f: F) -> Self {
+		match Self::try_from_fn(len, |i| Ok::<_, core::convert::Infallible>(f(i))) {
+			Ok(array) => array,
+		}
+	}
+
+	/// construct an array of `len` elements, where each element is `f(index)`,
+	/// stopping at the first error. the elements built so far are dropped.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array = Array::<8, _>::try_from_fn(3, |i| u8::try_from(i * 100));
+	/// assert_eq!(array, Ok(Array::from_parts([0, 100, 200])));
+	///
+	/// let array = Array::<8, _>::try_from_fn(4, |i| u8::try_from(i * 100));
+	/// assert!(array.is_err());
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `len` is greater than the array capacity.
+	///
+	/// ```should_panic
+	/// # use nyarray::array::Array;
+	/// let array = Array::<2, _>::try_from_fn(3, |i| Ok::<_, ()>(i)); // panics
+	/// ```
+	pub fn try_from_fn<E, F: FnMut(usize) -> Result<T, E>>(len: usize, mut f: F) -> Result<Self, E> {
+		assert!(len <= N, "length exceeds capacity");
+
+		let mut array = Self::new();
+
+		for i in 0..len {
+			unsafe {
+				// safety: just confirmed `len` elements fit
+				array.push_unchecked(f(i)?);
+			}
+		}
+
+		Ok(array)
+	}
+
+	/// construct an array by cloning every element of `slice`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array = Array::<8, i32>::from_slice(&[1, 2, 3]);
+	/// assert_eq!(array, [1, 2, 3]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `slice` has more elements than the array capacity.
+	/// for a non-panicking version, see [`Self::try_from_slice()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array::Array;
+	/// let array = Array::<2, i32>::from_slice(&[1, 2, 3]); // panics
+	/// ```
+	#[inline]
+	pub fn from_slice(slice: &[T]) -> Self
+	where
+		T: Clone,
+	{
+		let mut array = Self::new();
+		array.extend_from_slice(slice);
+		array
+	}
+
+	/// construct an array by cloning every element of `slice`. returns `Err(())`
+	/// if `slice` has more elements than the array capacity.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array = Array::<4, i32>::try_from_slice(&[1, 2, 3]);
+	/// assert_eq!(array, Ok(Array::from_parts([1, 2, 3])));
+	///
+	/// let array = Array::<2, i32>::try_from_slice(&[1, 2, 3]);
+	/// assert_eq!(array, Err(()));
+	/// ```
+	#[inline]
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn try_from_slice(slice: &[T]) -> Result<Self, ()>
+	where
+		T: Clone,
+	{
+		let mut array = Self::new();
+		array.try_extend_from_slice(slice)?;
+		Ok(array)
+	}
+
+	/// construct an array by copying every element of `slice`. unlike
+	/// [`Self::from_slice()`], this can be used in const contexts, like
+	/// initializing a `static`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// static TABLE: Array<8, u8> = Array::from_const_slice(&[1, 2, 4, 8]);
+	///
+	/// assert_eq!(TABLE, [1, 2, 4, 8]);
+	/// assert_eq!(TABLE.capacity(), 8);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `slice` has more elements than the array
+	/// capacity. in a const context, this is a compile error instead.
+	///
+	/// ```should_panic
+	/// # use nyarray::array::Array;
+	/// let array = Array::<2, u8>::from_const_slice(&[1, 2, 3]); // panics
+	/// ```
+	#[inline]
+	pub const fn from_const_slice(slice: &[T]) -> Self
+	where
+		T: Copy,
+	{
+		assert!(slice.len() <= N, "slice exceeds capacity");
+
+		let mut array = Self::new();
+
+		unsafe {
+			// safety: `slice` fits, and `T: Copy` so the elements can be duplicated
+			core::ptr::copy_nonoverlapping(slice.as_ptr(), array.as_mut_ptr(), slice.len());
+			array.set_len(slice.len());
+		}
+
+		array
+	}
+
+	/// returns a copy of the array. this does the same as [`Clone::clone()`],
+	/// but as `T: Copy`, the elements are copied in a single
+	/// [`core::ptr::copy_nonoverlapping()`] rather than cloned one by one,
+	/// and it can be used in const contexts.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 8];
+	/// let copy = array.copy();
+	///
+	/// assert_eq!(copy, [1, 2, 3]);
+	/// assert_eq!(copy.capacity(), 8);
+	/// ```
+	#[inline]
+	pub const fn copy(&self) -> Self
+	where
+		T: Copy,
+	{
+		let mut array = Self::new();
+
+		unsafe {
+			// safety: both arrays have the same capacity, and `T: Copy` so the
+			// elements can be duplicated
+			core::ptr::copy_nonoverlapping(self.as_ptr(), array.as_mut_ptr(), self.len());
+			array.set_len(self.len());
+		}
+
+		array
+	}
+
 	/// returns the total number of elements the array can hold.
 	/// this function always returns the const `N` parameter of this array.
 	///
@@ -332,1088 +591,5816 @@ impl<const N: usize, T> Array<N, T> {
 		}
 	}
 
-	/// returns a raw pointer to the internal buffer.
+	/// returns a reference to the array as a fixed-size array `[T; C]`,
+	/// or `None` if [`Self::len()`] is not exactly `C`.
 	///
-	/// this pointer is valid so long as this array is valid. if the array is
-	/// dropped, or even moved, the pointer is immediately invalid.
-	#[inline]
-	pub const fn as_ptr(&self) -> *const T {
-		self.buf.as_ptr() as *const T
-	}
-
-	/// returns a mutable raw pointer to the internal buffer.
+	/// ## examples
 	///
-	/// this pointer is valid so long as this array is valid. if the array is
-	/// dropped, or even moved, the pointer is immediately invalid.
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 8];
+	///
+	/// if let Some([a, b, c]) = array.as_array::<3>() {
+	///     assert_eq!(a + b + c, 6);
+	/// } else {
+	///     unreachable!();
+	/// }
+	///
+	/// assert!(array.as_array::<2>().is_none());
+	/// assert!(array.as_array::<4>().is_none());
+	/// ```
 	#[inline]
-	pub const fn as_mut_ptr(&mut self) -> *mut T {
-		self.buf.as_mut_ptr() as *mut T
+	pub const fn as_array<const C: usize>(&self) -> Option<&[T; C]> {
+		if self.len() != C {
+			return None;
+		}
+
+		unsafe {
+			// safety: `[T; C]` has the same alignment as `T`, and the first
+			// `C == len` elements are initialized
+			Some(&*(self.as_ptr() as *const [T; C]))
+		}
 	}
 
-	/// removes all elements from the array.
+	/// returns a mutable reference to the array as a fixed-size array `[T; C]`,
+	/// or `None` if [`Self::len()`] is not exactly `C`.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 4];
-	/// array.clear();
-	/// assert!(array.is_empty());
+	/// let mut array = array![1, 2, 3 => 8];
+	///
+	/// if let Some([a, _, c]) = array.as_array_mut::<3>() {
+	///     core::mem::swap(a, c);
+	/// }
+	/// assert_eq!(array, [3, 2, 1]);
+	///
+	/// assert!(array.as_array_mut::<2>().is_none());
 	/// ```
 	#[inline]
-	pub fn clear(&mut self) {
+	pub const fn as_array_mut<const C: usize>(&mut self) -> Option<&mut [T; C]> {
+		if self.len() != C {
+			return None;
+		}
+
 		unsafe {
-			let elements = self.as_mut_slice() as *mut [T];
-			core::ptr::drop_in_place(elements);
-			self.set_len(0);
+			// safety: `[T; C]` has the same alignment as `T`, and the first
+			// `C == len` elements are initialized
+			Some(&mut *(self.as_mut_ptr() as *mut [T; C]))
 		}
 	}
 
-	/// add an element to the end of the array.
+	/// splits the array into `C`-element chunks, and the remainder that
+	/// doesn't fill a whole chunk.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![=> 4];
-	/// array.push(1);
-	/// array.push(2);
-	/// array.push(3);
-	/// assert_eq!(array, [1, 2, 3]);
+	/// let array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// let (chunks, rest) = array.as_chunks::<2>();
+	/// assert_eq!(chunks, [[1, 2], [3, 4]]);
+	/// assert_eq!(rest, [5]);
 	/// ```
 	///
 	/// ## panics
 	///
-	/// this method panics if there isn't enough space for another element.
-	/// for a non-panicking version, see [`Self::push_checked()`].
+	/// this method panics if `C` is `0`.
 	///
 	/// ```should_panic
 	/// # use nyarray::array;
-	/// let mut array = array![=> 4];
-	/// array.push(1);
-	/// array.push(2);
-	/// array.push(3);
-	/// array.push(4);
-	/// array.push(5); // panics
+	/// let array = array![1, 2, 3 => 8];
+	/// array.as_chunks::<0>(); // panics
 	/// ```
 	#[inline]
-	pub const fn push(&mut self, value: T) {
-		if self.len() == self.capacity() {
-			panic!("push exceeds capacity");
-		} else {
-			unsafe {
-				// safety: just confirmed there is enough space for another element
-				self.push_unchecked(value);
-			}
-		}
-		// todo: go back to using `Self::push_checked()` when const Drop is stable
-		/*
-		if self.push_checked(value).is_err() {
-			panic!("push exceeds capacity");
+	pub const fn as_chunks<const C: usize>(&self) -> (&[[T; C]], &[T]) {
+		assert!(C != 0, "chunk size must be non-zero");
+
+		let count = self.len() / C;
+		let (chunks, rest) = self.as_slice().split_at(count * C);
+
+		unsafe {
+			// safety: `[T; C]` has the same alignment as `T`, and `chunks` is
+			// exactly `count * C` elements long
+			(core::slice::from_raw_parts(chunks.as_ptr() as *const [T; C], count), rest)
 		}
-		*/
 	}
 
-	/// add an element to the end of the array. returns `Err(T)` if
-	/// there is not enough capacity.
+	/// splits the array into mutable `C`-element chunks, and the remainder
+	/// that doesn't fill a whole chunk.
 	///
 	/// ## examples
 	///
 	/// ```
-	/// # fn main() -> Result<(), i32> {
 	/// # use nyarray::array;
-	/// let mut array = array![=> 4];
-	/// array.push_checked(1)?;
-	/// array.push_checked(2)?;
-	/// array.push_checked(3)?;
-	/// assert_eq!(array, [1, 2, 3]);
-	/// # Ok(())
-	/// # }
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// let (chunks, rest) = array.as_chunks_mut::<2>();
+	/// for [a, b] in chunks {
+	///     core::mem::swap(a, b);
+	/// }
+	/// rest[0] = 0;
+	/// assert_eq!(array, [2, 1, 4, 3, 0]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `C` is `0`.
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.as_chunks_mut::<0>(); // panics
 	/// ```
 	#[inline]
-	pub const fn push_checked(&mut self, value: T) -> Result<(), T> {
-		if self.len() == self.capacity() {
-			Err(value)
-		} else {
-			unsafe {
-				// safety: just confirmed there is enough space for another element
-				self.push_unchecked(value);
-			}
-			Ok(())
+	pub const fn as_chunks_mut<const C: usize>(&mut self) -> (&mut [[T; C]], &mut [T]) {
+		assert!(C != 0, "chunk size must be non-zero");
+
+		let count = self.len() / C;
+		let (chunks, rest) = self.as_mut_slice().split_at_mut(count * C);
+
+		unsafe {
+			// safety: `[T; C]` has the same alignment as `T`, and `chunks` is
+			// exactly `count * C` elements long
+			(core::slice::from_raw_parts_mut(chunks.as_mut_ptr() as *mut [T; C], count), rest)
 		}
 	}
 
-	/// add an element to the end of the array.
+	/// returns the initialized elements, and the remaining spare capacity.
 	///
-	/// this is the unsafe version of this method. see [`Self::push()`] or
-	/// [`Self::push_checked()`] for safe versions of this.
+	/// the spare capacity is uninitialized memory, and is returned as
+	/// `MaybeUninit<T>`; it is only ever safe to read if it was written to,
+	/// which can't happen through this shared reference.
 	///
-	/// ## safety
+	/// ## examples
 	///
-	/// there must be enough capacity in the array for at least one more element
-	/// before calling this method. ie; [`Self::len()`] `<` [`Self::capacity()`].
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 8];
+	///
+	/// let (init, spare) = array.initialized_and_spare();
+	///
+	/// assert_eq!(init, [1, 2, 3]);
+	/// assert_eq!(spare.len(), 5);
+	/// assert_eq!(init.len() + spare.len(), array.capacity());
+	/// ```
+	#[inline]
+	pub const fn initialized_and_spare(&self) -> (&[T], &[core::mem::MaybeUninit<T>]) {
+		let (_, spare) = self.buf.split_at(self.len);
+		(self.as_slice(), spare)
+	}
+
+	/// returns the spare capacity of the array, `len..N`, as a mutable slice of
+	/// `MaybeUninit<T>`.
+	///
+	/// this can be used to write elements directly into the array. elements
+	/// written here are not part of the array until [`Self::set_len()`] is
+	/// called, and the caller must initialize every element up to the new
+	/// length before doing so.
 	///
 	/// ## examples
 	///
 	/// ```
-	/// # fn main() -> Result<(), i32> {
 	/// # use nyarray::array;
-	/// let mut array = array![=> 4];
+	/// # use nyarray::array::Array;
+	/// let mut array: Array<8, u32> = array![1, 2, 3 => 8];
+	///
+	/// let spare = array.spare_capacity_mut();
+	/// assert_eq!(spare.len(), 5);
+	///
+	/// spare[0].write(4);
+	/// spare[1].write(5);
+	///
 	/// unsafe {
-	///     // safety: array has capacity of 4 elements.
-	///     array.push_unchecked(1);
-	///     array.push_unchecked(2);
-	///     array.push_unchecked(3);
-	///     array.push_unchecked(4);
-	///    // array.push_unchecked(5); // UB
+	///     // safety: the two elements after `len` were just initialized
+	///     array.set_len(array.len() + 2);
 	/// }
-	/// assert_eq!(array, [1, 2, 3, 4]);
-	/// # Ok(())
-	/// # }
-	/// ```
-	#[inline]
-	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
-	pub const unsafe fn push_unchecked(&mut self, value: T) {
-		unsafe {
-			let len = self.len();
-
-			// safety: caller ensures there is enough space for another element
-			let ptr = self.as_mut_ptr().add(len);
-
-			core::ptr::write(ptr, value);
-
-			// set length to accomodate for new element
-			self.set_len(len + 1);
-		}
-	}
-
-	/// remove and return an element from the end of the array.
-	/// returns `None` if the array is empty.
 	///
-	/// ## examples
-	///
-	/// ```
-	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 4];
-	/// assert_eq!(array.pop(), Some(3));
-	/// assert_eq!(array.pop(), Some(2));
-	/// assert_eq!(array.pop(), Some(1));
-	/// assert_eq!(array.pop(), None);
+	/// assert_eq!(array, [1, 2, 3, 4, 5]);
 	/// ```
 	#[inline]
-	pub const fn pop(&mut self) -> Option<T> {
-		if self.is_empty() {
-			None
-		} else {
-			unsafe {
-				// safety: just confirmed there is an element in the array
-				Some(self.pop_unchecked())
-			}
-		}
+	pub const fn spare_capacity_mut(&mut self) -> &mut [core::mem::MaybeUninit<T>] {
+		let (_, spare) = self.buf.split_at_mut(self.len);
+		spare
 	}
 
-	/// remove and return an element from the end of the array.
+	/// grow the array to `initialized` elements, after writing them into
+	/// [`Self::spare_capacity_mut()`].
 	///
-	/// this is the unsafe version of this method. see [`Self::pop()`] for
-	/// the safe version.
+	/// unlike [`Self::set_len()`], this can only grow the array, and checks
+	/// that `initialized` stays within the capacity.
 	///
 	/// ## safety
 	///
-	/// there must be at least one element in the array prior to calling
-	/// this method. ie; [`Self::len()`] `!= 0`
+	/// elements `self.len()..initialized` must be initialized.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 4];
+	/// # use nyarray::array::Array;
+	/// let mut array: Array<8, u32> = array![1, 2 => 8];
+	///
+	/// let spare = array.spare_capacity_mut();
+	/// spare[0].write(3);
+	/// spare[1].write(4);
 	///
 	/// unsafe {
-	///     // safety: array has 3 elements
-	///     assert_eq!(array.pop_unchecked(), 3);
-	///     assert_eq!(array.pop_unchecked(), 2);
-	///     assert_eq!(array.pop_unchecked(), 1);
-	///     // array.pop_unchecked() // UB
+	///     // safety: the two elements after `len` were just initialized
+	///     array.commit(4);
 	/// }
 	///
-	/// assert!(array.is_empty());
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `initialized` is lesser than [`Self::len()`], or
+	/// greater than [`Self::capacity()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// unsafe { array.commit(5) }; // panics
 	/// ```
 	#[inline]
 	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
-	pub const unsafe fn pop_unchecked(&mut self) -> T {
-		unsafe {
-			// safety: caller ensures there is at least one element.
-
-			// underflows if no elements
-			let len = self.len() - 1;
-
-			// first set len to new len
-			self.set_len(len);
-
-			core::ptr::read(self.as_ptr().add(len))
+	pub const unsafe fn commit(&mut self, initialized: usize) {
+		if initialized < self.len || initialized > N {
+			panic!("commit out of bounds");
 		}
+
+		self.len = initialized;
 	}
 
-	/// insert an element into any index of the array, shifting
-	/// all elements after towards the end.
+	/// returns `true` if the array contains an element equal to `x`.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 6];
+	/// let array = array![1, 2, 3 => 8];
 	///
-	/// array.insert(2, 10);
-	/// assert_eq!(array, [1, 2, 10, 3]);
+	/// assert!(array.contains(&2));
+	/// assert!(!array.contains(&4));
+	/// ```
+	#[inline]
+	pub fn contains(&self, x: &T) -> bool
+	where
+		T: PartialEq,
+	{
+		self.as_slice().contains(x)
+	}
+
+	/// returns the index of the first element for which `pred` returns `true`.
+	/// this is the same as `array.iter().position(pred)`.
 	///
-	/// array.insert(0, 20);
-	/// assert_eq!(array, [20, 1, 2, 10, 3]);
+	/// ## examples
 	///
-	/// array.insert(5, 30);
-	/// assert_eq!(array, [20, 1, 2, 10, 3, 30]);
 	/// ```
+	/// # use nyarray::array;
+	/// let array = array![10, 20, 30 => 4];
 	///
-	/// ## panics
+	/// assert_eq!(array.position(|x| *x == 20), Some(1));
+	/// assert_eq!(array.position(|x| *x > 10), Some(1));
+	/// assert_eq!(array.position(|x| *x > 30), None);
+	/// ```
+	#[inline]
+	pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+		self.as_slice().iter().position(pred)
+	}
+
+	/// returns the index of the last element for which `pred` returns `true`.
+	/// this is the same as `array.iter().rposition(pred)`.
 	///
-	/// this method panics if there isn't enough space for another element,
-	/// or if `index` is not `0..self.len()`.
-	/// for a non-panicking version, see [`Self::insert_checked()`].
+	/// ## examples
 	///
-	/// ```should_panic
+	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 4];
-	/// array.insert(0, 4); // okay
-	/// array.insert(0, 5); // panics
+	/// let array = array![10, 20, 30 => 4];
+	///
+	/// assert_eq!(array.rposition(|x| *x == 20), Some(1));
+	/// assert_eq!(array.rposition(|x| *x > 10), Some(2));
+	/// assert_eq!(array.rposition(|x| *x < 10), None);
 	/// ```
 	#[inline]
-	pub const fn insert(&mut self, index: usize, element: T) {
-		if index > self.len() {
-			panic!("index out of bounds");
-		}
-
-		if self.len() + 1 > self.capacity() {
-			panic!("insert exceeds capacity");
-		}
-
-		unsafe {
-			// safety: just confirmed index is in bounds and there is enough capacity
-			self.insert_unchecked(index, element);
-		}
-		// todo: edit when const Drop
-		/*
-		if self.insert_checked(index, element).is_err() {
-			if index > self.len() {
-				panic!("index out of bounds");
-			} else {
-				panic!("insert exceeds capacity");
-			}
-		}
-		*/
+	pub fn rposition<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+		self.as_slice().iter().rposition(pred)
 	}
 
-	/// insert an element into any index of the array, shifting
-	/// all elements after towards the end. returns Err(T) if there
-	/// is not enough capacity, or if `index` is not `0..=self.len()`.
+	/// returns a reference to the first element, or `None` if empty.
 	///
 	/// ## examples
 	///
 	/// ```
-	/// # fn main() -> Result<(), i32> {
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 6];
+	/// # use nyarray::array::Array;
+	/// let array = array![1, 2, 3 => 8];
+	/// assert_eq!(array.first(), Some(&1));
 	///
-	/// array.insert_checked(2, 10)?;
-	/// assert_eq!(array, [1, 2, 10, 3]);
+	/// let array = Array::<8, i32>::new();
+	/// assert_eq!(array.first(), None);
+	/// ```
+	#[inline]
+	pub const fn first(&self) -> Option<&T> {
+		self.as_slice().first()
+	}
+
+	/// returns a reference to the last element, or `None` if empty.
 	///
-	/// array.insert_checked(0, 20)?;
-	/// assert_eq!(array, [20, 1, 2, 10, 3]);
+	/// ## examples
 	///
-	/// array.insert_checked(5, 30)?;
-	/// assert_eq!(array, [20, 1, 2, 10, 3, 30]);
-	/// # Ok(())
-	/// # }
+	/// ```
+	/// # use nyarray::array;
+	/// # use nyarray::array::Array;
+	/// let array = array![1, 2, 3 => 8];
+	/// assert_eq!(array.last(), Some(&3));
+	///
+	/// let array = Array::<8, i32>::new();
+	/// assert_eq!(array.last(), None);
 	/// ```
 	#[inline]
-	pub const fn insert_checked(&mut self, index: usize, element: T) -> Result<(), T> {
-		if index > self.len() {
-			return Err(element);
-		}
-
-		if self.len() + 1 > self.capacity() {
-			return Err(element);
-		}
-
-		unsafe {
-			// safety: just confirmed index is in bounds and there is enough capacity
-			self.insert_unchecked(index, element);
-		}
-
-		Ok(())
+	pub const fn last(&self) -> Option<&T> {
+		self.as_slice().last()
 	}
 
-	/// insert an element into any index of the array, shifting
-	/// all elements after towards the end.
+	/// returns a mutable reference to the first element, or `None` if empty.
 	///
-	/// this is the unsafe version of this method. see [`Self::insert_checked()`] or
-	/// [`Self::insert()`] for safe versions.
+	/// ## examples
 	///
-	/// ## safety
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
 	///
-	/// - there must be enough capacity in the array for at least one more element
-	///   prior to calling this method. ie; [`Self::len()`] `<` [`Self::capacity()`].
-	/// - `index` `<=` [`Self::len()`]
+	/// if let Some(first) = array.first_mut() {
+	///     *first = 5;
+	/// }
+	/// assert_eq!(array, [5, 2, 3]);
+	/// ```
+	#[inline]
+	pub const fn first_mut(&mut self) -> Option<&mut T> {
+		self.as_mut_slice().first_mut()
+	}
+
+	/// returns a mutable reference to the last element, or `None` if empty.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![=> 4];
+	/// let mut array = array![1, 2, 3 => 8];
 	///
-	/// unsafe {
-	///     // safety: array has capacity of 4 elements.
-	///     array.insert_unchecked(0, 1);
-	///     array.insert_unchecked(0, 2);
-	///     array.insert_unchecked(0, 3);
-	///     array.insert_unchecked(0, 4);
-	///     // array.insert_unchecked(0, 5); // UB
+	/// if let Some(last) = array.last_mut() {
+	///     *last = 5;
 	/// }
-	///
-	/// assert_eq!(array, [4, 3, 2, 1]);
+	/// assert_eq!(array, [1, 2, 5]);
 	/// ```
 	#[inline]
-	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
-	pub const unsafe fn insert_unchecked(&mut self, index: usize, element: T) {
-		unsafe {
-			let len = self.len();
-
-			let ptr = self.as_mut_ptr().add(index);
-
-			if index != len {
-				core::ptr::copy(ptr, ptr.add(1), len - index);
-			}
-
-			core::ptr::write(ptr, element);
-
-			self.set_len(len + 1);
-		}
+	pub const fn last_mut(&mut self) -> Option<&mut T> {
+		self.as_mut_slice().last_mut()
 	}
 
-	/// insert an element into any index of the array, moving the element
-	/// that was previously there to the end.
+	/// returns a reference to an element or subslice, or `None` if `index`
+	/// is out of bounds.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 6];
+	/// let array = array![1, 2, 3 => 8];
 	///
-	/// array.swap_insert(2, 10);
-	/// assert_eq!(array, [1, 2, 10, 3]);
+	/// assert_eq!(array.get(1), Some(&2));
+	/// assert_eq!(array.get(3), None);
 	///
-	/// array.swap_insert(0, 20);
-	/// assert_eq!(array, [20, 2, 10, 3, 1]);
+	/// assert_eq!(array.get(0..2), Some(&[1, 2][..]));
+	/// assert_eq!(array.get(2..4), None);
+	/// ```
+	#[inline]
+	pub fn get<I: core::slice::SliceIndex<[T]>>(&self, index: I) -> Option<&I::Output> {
+		self.as_slice().get(index)
+	}
+
+	/// returns a mutable reference to an element or subslice, or `None` if
+	/// `index` is out of bounds.
+	///
+	/// ## examples
 	///
-	/// array.swap_insert(5, 30);
-	/// assert_eq!(array, [20, 2, 10, 3, 1, 30]);
 	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
 	///
-	/// ## panics
+	/// if let Some(x) = array.get_mut(1) {
+	///     *x = 5;
+	/// }
+	/// assert_eq!(array, [1, 5, 3]);
 	///
-	/// this method panics if there isn't enough space for another element,
-	/// or if `index` is not `0..=self.len()`.
-	/// for a non-panicking version, see [`Self::swap_insert_checked()`].
+	/// if let Some(slice) = array.get_mut(1..) {
+	///     slice.fill(0);
+	/// }
+	/// assert_eq!(array, [1, 0, 0]);
 	///
-	/// ```should_panic
-	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 4];
-	/// array.swap_insert(0, 4); // okay
-	/// array.swap_insert(0, 5); // panics
+	/// assert!(array.get_mut(4..).is_none());
 	/// ```
 	#[inline]
-	pub const fn swap_insert(&mut self, index: usize, element: T) {
-		if index > self.len() {
-			panic!("index out of bounds");
-		}
+	pub fn get_mut<I: core::slice::SliceIndex<[T]>>(&mut self, index: I) -> Option<&mut I::Output> {
+		self.as_mut_slice().get_mut(index)
+	}
 
-		if self.len() + 1 > self.capacity() {
-			panic!("insert exceeds capacity");
-		}
+	/// returns a raw pointer to the internal buffer.
+	///
+	/// this pointer is valid so long as this array is valid. if the array is
+	/// dropped, or even moved, the pointer is immediately invalid.
+	#[inline]
+	pub const fn as_ptr(&self) -> *const T {
+		self.buf.as_ptr() as *const T
+	}
 
-		unsafe {
-			// safety: just confirmed index is in bounds and there is enough capacity
-			self.swap_insert_unchecked(index, element);
-		}
-		// todo: edit when const Drop
-		/*
-		if self.swap_insert_checked(index, element).is_err() {
-			if index > self.len() {
-				panic!("index out of bounds");
-			} else {
-				panic!("insert exceeds capacity");
-			}
-		}
-		*/
-	}
-
-	/// insert an element into any index of the array, moving the element
-	/// that was previously there to the end. returns Err(T) if there
-	/// is not enough capacity, or if `index` is not `0..=self.len()`.
-	///
-	/// ## examples
-	///
-	/// ```
-	/// # fn main() -> Result<(), i32> {
-	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3 => 6];
-	///
-	/// array.swap_insert_checked(2, 10)?;
-	/// assert_eq!(array, [1, 2, 10, 3]);
-	///
-	/// array.swap_insert_checked(0, 20)?;
-	/// assert_eq!(array, [20, 2, 10, 3, 1]);
+	/// returns a mutable raw pointer to the internal buffer.
 	///
-	/// array.swap_insert_checked(5, 30)?;
-	/// assert_eq!(array, [20, 2, 10, 3, 1, 30]);
-	/// # Ok(())
-	/// # }
-	/// ```
+	/// this pointer is valid so long as this array is valid. if the array is
+	/// dropped, or even moved, the pointer is immediately invalid.
 	#[inline]
-	pub const fn swap_insert_checked(&mut self, index: usize, element: T) -> Result<(), T> {
-		if index > self.len() {
-			return Err(element);
-		}
-
-		if self.len() + 1 > self.capacity() {
-			return Err(element);
-		}
-
-		unsafe {
-			// safety: just confirmed index is in bounds and there is enough capacity
-			self.swap_insert_unchecked(index, element);
-		}
-
-		Ok(())
+	pub const fn as_mut_ptr(&mut self) -> *mut T {
+		self.buf.as_mut_ptr() as *mut T
 	}
 
-	/// insert an element into any index of the array, moving the element
-	/// that was previously there to the end.
-	///
-	/// this is the unsafe version of this method. see [`Self::swap_insert_checked()`]
-	/// or [`Self::swap_insert()`] for safe versions.
-	///
-	/// ## safety
-	///
-	/// - there must be enough capacity in the array for at least one more element
-	///   prior to calling this method. ie; [`Self::len()`] `<` [`Self::capacity()`].
-	/// - `index` `<=` [`Self::len()`]
+	/// removes all elements from the array.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![=> 4];
-	///
-	/// unsafe {
-	///     // safety: array has a capacity of 4
-	///     array.swap_insert_unchecked(0, 1);
-	///     array.swap_insert_unchecked(0, 2);
-	///     array.swap_insert_unchecked(0, 3);
-	///     array.swap_insert_unchecked(0, 4);
-	///     // array.swap_insert_unchecked(0, 5); // UB
-	/// }
-	///
-	/// assert_eq!(array, [4, 1, 2, 3])
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.clear();
+	/// assert!(array.is_empty());
 	/// ```
 	#[inline]
-	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
-	pub const unsafe fn swap_insert_unchecked(&mut self, index: usize, element: T) {
+	pub fn clear(&mut self) {
 		unsafe {
-			let len = self.len();
-
-			let ptr = self.as_mut_ptr();
-
-			// safety: caller ensures `index` is in bounds and there is enough
-			// space for another element.
-			let old_ptr = ptr.add(index);
-			let new_ptr = ptr.add(len);
-
-			core::ptr::write(new_ptr, element);
-			core::ptr::swap(old_ptr, new_ptr);
-
-			self.set_len(len + 1);
+			let elements = self.as_mut_slice() as *mut [T];
+			core::ptr::drop_in_place(elements);
+			self.set_len(0);
 		}
 	}
 
-	/// remove and return an element out of any index of the array,
-	/// shifting all elements after towards the start.
+	/// add an element to the end of the array.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
-	///
-	/// assert_eq!(array.remove(0), 1);
-	/// assert_eq!(array, [2, 3, 4, 5, 6]);
-	///
-	/// assert_eq!(array.remove(2), 4);
-	/// assert_eq!(array, [2, 3, 5, 6]);
-	///
-	/// assert_eq!(array.remove(3), 6);
-	/// assert_eq!(array, [2, 3, 5]);
+	/// let mut array = array![=> 4];
+	/// array.push(1);
+	/// array.push(2);
+	/// array.push(3);
+	/// assert_eq!(array, [1, 2, 3]);
 	/// ```
 	///
 	/// ## panics
 	///
-	/// this method panics if `index` is not `0..self.len()`.
-	/// for a non-panicking version, see [`Self::remove_checked()`].
+	/// this method panics if there isn't enough space for another element.
+	/// for a non-panicking version, see [`Self::push_checked()`].
 	///
 	/// ```should_panic
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4 => 4];
-	/// array.remove(4); // panics
+	/// let mut array = array![=> 4];
+	/// array.push(1);
+	/// array.push(2);
+	/// array.push(3);
+	/// array.push(4);
+	/// array.push(5); // panics
 	/// ```
 	#[inline]
-	pub const fn remove(&mut self, index: usize) -> T {
-		if index >= self.len() {
-			panic!("index out of bounds");
-		}
-
-		unsafe {
-			self.remove_unchecked(index)
+	pub const fn push(&mut self, value: T) {
+		if self.len() == self.capacity() {
+			panic!("push exceeds capacity");
+		} else {
+			unsafe {
+				// safety: just confirmed there is enough space for another element
+				self.push_unchecked(value);
+			}
 		}
-		// todo: edit when const Drop
+		// todo: go back to using `Self::push_checked()` when const Drop is stable
 		/*
-		match self.remove_checked(index) {
-			Some(x) => x,
-			None => panic!("index out of bounds"),
+		if self.push_checked(value).is_err() {
+			panic!("push exceeds capacity");
 		}
 		*/
 	}
 
-	/// remove and return an element out of any index of the array,
-	/// shifting all elements after towards the start. returns `None`
-	/// if `index` is not `0..self.len()`.
+	/// add an element to the end of the array. returns `Err(T)` if
+	/// there is not enough capacity.
 	///
 	/// ## examples
 	///
 	/// ```
+	/// # fn main() -> Result<(), i32> {
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
-	///
-	/// assert_eq!(array.remove_checked(0), Some(1));
-	/// assert_eq!(array, [2, 3, 4, 5, 6]);
-	///
-	/// assert_eq!(array.remove_checked(2), Some(4));
-	/// assert_eq!(array, [2, 3, 5, 6]);
-	///
-	/// assert_eq!(array.remove_checked(3), Some(6));
-	/// assert_eq!(array, [2, 3, 5]);
-	///
-	/// assert_eq!(array.remove_checked(3), None);
-	/// assert_eq!(array, [2, 3, 5]);
+	/// let mut array = array![=> 4];
+	/// array.push_checked(1)?;
+	/// array.push_checked(2)?;
+	/// array.push_checked(3)?;
+	/// assert_eq!(array, [1, 2, 3]);
+	/// # Ok(())
+	/// # }
 	/// ```
 	#[inline]
-	pub const fn remove_checked(&mut self, index: usize) -> Option<T> {
-		if index >= self.len() {
-			return None;
-		}
-
-		unsafe {
-			// safety: just confirmed index is in bounds
-			Some(self.remove_unchecked(index))
+	pub const fn push_checked(&mut self, value: T) -> Result<(), T> {
+		if self.len() == self.capacity() {
+			Err(value)
+		} else {
+			unsafe {
+				// safety: just confirmed there is enough space for another element
+				self.push_unchecked(value);
+			}
+			Ok(())
 		}
 	}
 
-	/// remove and return an element out of any index of the array,
-	/// shifting all elements after towards the start.
+	/// add an element to the end of the array.
 	///
-	/// this is the unsafe version of this method. see [`Self::remove_checked()`]
-	/// or [`Self::remove()`] for safe versions.
+	/// this is the unsafe version of this method. see [`Self::push()`] or
+	/// [`Self::push_checked()`] for safe versions of this.
 	///
 	/// ## safety
 	///
-	/// - there must be at least one element in the array prior to calling
-	///   this method. ie; [`Self::len()`] `!= 0`
-	/// - `index` `<` [`Self::len()`]
+	/// there must be enough capacity in the array for at least one more element
+	/// before calling this method. ie; [`Self::len()`] `<` [`Self::capacity()`].
 	///
 	/// ## examples
 	///
 	/// ```
+	/// # fn main() -> Result<(), i32> {
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4 => 4];
-	///
+	/// let mut array = array![=> 4];
 	/// unsafe {
-	///     // safety: array has 4 elements.
-	///     assert_eq!(array.remove_unchecked(0), 1);
-	///     assert_eq!(array.remove_unchecked(0), 2);
-	///     assert_eq!(array.remove_unchecked(0), 3);
-	///     assert_eq!(array.remove_unchecked(0), 4);
-	///     // array.remove_unchecked(0) // UB
+	///     // safety: array has capacity of 4 elements.
+	///     array.push_unchecked(1);
+	///     array.push_unchecked(2);
+	///     array.push_unchecked(3);
+	///     array.push_unchecked(4);
+	///    // array.push_unchecked(5); // UB
 	/// }
-	///
-	/// assert!(array.is_empty());
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	/// # Ok(())
+	/// # }
 	/// ```
 	#[inline]
 	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
-	pub const unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+	pub const unsafe fn push_unchecked(&mut self, value: T) {
 		unsafe {
 			let len = self.len();
 
-			// safety: caller ensures index is in bounds and there is at least one element
-			let ptr = self.as_mut_ptr().add(index);
-
-			let old = core::ptr::read(ptr);
-
-			core::ptr::copy(ptr.add(1), ptr, len - index - 1);
+			// safety: caller ensures there is enough space for another element
+			let ptr = self.as_mut_ptr().add(len);
 
-			self.set_len(len - 1);
+			core::ptr::write(ptr, value);
 
-			old
+			// set length to accomodate for new element
+			self.set_len(len + 1);
 		}
 	}
 
-	/// remove and return an element from any index of the array,
-	/// moving the element that was previously at the end to there.
+	/// remove and return an element from the end of the array.
+	/// returns `None` if the array is empty.
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
-	///
-	/// assert_eq!(array.swap_remove(0), 1);
-	/// assert_eq!(array, [6, 2, 3, 4, 5]);
-	///
-	/// assert_eq!(array.swap_remove(2), 3);
-	/// assert_eq!(array, [6, 2, 5, 4]);
+	/// let mut array = array![1, 2, 3 => 4];
+	/// assert_eq!(array.pop(), Some(3));
+	/// assert_eq!(array.pop(), Some(2));
+	/// assert_eq!(array.pop(), Some(1));
+	/// assert_eq!(array.pop(), None);
+	/// ```
+	#[inline]
+	pub const fn pop(&mut self) -> Option<T> {
+		if self.is_empty() {
+			None
+		} else {
+			unsafe {
+				// safety: just confirmed there is an element in the array
+				Some(self.pop_unchecked())
+			}
+		}
+	}
+
+	/// remove and return an element from the end of the array.
 	///
-	/// assert_eq!(array.swap_remove(3), 4);
-	/// assert_eq!(array, [6, 2, 5]);
+	/// this is the unsafe version of this method. see [`Self::pop()`] for
+	/// the safe version.
+	///
+	/// ## safety
+	///
+	/// there must be at least one element in the array prior to calling
+	/// this method. ie; [`Self::len()`] `!= 0`
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	///
+	/// unsafe {
+	///     // safety: array has 3 elements
+	///     assert_eq!(array.pop_unchecked(), 3);
+	///     assert_eq!(array.pop_unchecked(), 2);
+	///     assert_eq!(array.pop_unchecked(), 1);
+	///     // array.pop_unchecked() // UB
+	/// }
+	///
+	/// assert!(array.is_empty());
+	/// ```
+	#[inline]
+	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
+	pub const unsafe fn pop_unchecked(&mut self) -> T {
+		unsafe {
+			// safety: caller ensures there is at least one element.
+
+			// underflows if no elements
+			let len = self.len() - 1;
+
+			// first set len to new len
+			self.set_len(len);
+
+			core::ptr::read(self.as_ptr().add(len))
+		}
+	}
+
+	/// insert an element into any index of the array, shifting
+	/// all elements after towards the end.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 6];
+	///
+	/// array.insert(2, 10);
+	/// assert_eq!(array, [1, 2, 10, 3]);
+	///
+	/// array.insert(0, 20);
+	/// assert_eq!(array, [20, 1, 2, 10, 3]);
+	///
+	/// array.insert(5, 30);
+	/// assert_eq!(array, [20, 1, 2, 10, 3, 30]);
 	/// ```
 	///
 	/// ## panics
 	///
-	/// this method panics if `index` is not `0..=self.len()`.
-	/// for a non-panicking version, see [`Self::swap_remove_checked()`].
+	/// this method panics if there isn't enough space for another element,
+	/// or if `index` is not `0..self.len()`.
+	/// for a non-panicking version, see [`Self::insert_checked()`].
 	///
 	/// ```should_panic
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4 => 4];
-	/// array.swap_remove(4); // panics
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.insert(0, 4); // okay
+	/// array.insert(0, 5); // panics
 	/// ```
 	#[inline]
-	pub const fn swap_remove(&mut self, index: usize) -> T {
-		if index >= self.len() {
+	pub const fn insert(&mut self, index: usize, element: T) {
+		if index > self.len() {
 			panic!("index out of bounds");
 		}
 
-		unsafe {
-			self.swap_remove_unchecked(index)
+		if self.len() + 1 > self.capacity() {
+			panic!("insert exceeds capacity");
 		}
 
+		unsafe {
+			// safety: just confirmed index is in bounds and there is enough capacity
+			self.insert_unchecked(index, element);
+		}
 		// todo: edit when const Drop
+		/*
+		if self.insert_checked(index, element).is_err() {
+			if index > self.len() {
+				panic!("index out of bounds");
+			} else {
+				panic!("insert exceeds capacity");
+			}
+		}
+		*/
 	}
 
-	/// remove and return an element from any index of the array,
-	/// moving the element that was previously at the end to there.
-	/// returns `None` if `index` is not `0..self.len()`.
+	/// insert an element into any index of the array, shifting
+	/// all elements after towards the end. returns Err(T) if there
+	/// is not enough capacity, or if `index` is not `0..=self.len()`.
 	///
 	/// ## examples
 	///
 	/// ```
+	/// # fn main() -> Result<(), i32> {
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
-	///
-	/// assert_eq!(array.swap_remove_checked(0), Some(1));
-	/// assert_eq!(array, [6, 2, 3, 4, 5]);
+	/// let mut array = array![1, 2, 3 => 6];
 	///
-	/// assert_eq!(array.swap_remove_checked(2), Some(3));
-	/// assert_eq!(array, [6, 2, 5, 4]);
+	/// array.insert_checked(2, 10)?;
+	/// assert_eq!(array, [1, 2, 10, 3]);
 	///
-	/// assert_eq!(array.swap_remove_checked(3), Some(4));
-	/// assert_eq!(array, [6, 2, 5]);
+	/// array.insert_checked(0, 20)?;
+	/// assert_eq!(array, [20, 1, 2, 10, 3]);
 	///
-	/// assert_eq!(array.swap_remove_checked(3), None);
-	/// assert_eq!(array, [6, 2, 5]);
+	/// array.insert_checked(5, 30)?;
+	/// assert_eq!(array, [20, 1, 2, 10, 3, 30]);
+	/// # Ok(())
+	/// # }
 	/// ```
 	#[inline]
-	pub const fn swap_remove_checked(&mut self, index: usize) -> Option<T> {
-		if index >= self.len() {
-			return None;
+	pub const fn insert_checked(&mut self, index: usize, element: T) -> Result<(), T> {
+		if index > self.len() {
+			return Err(element);
+		}
+
+		if self.len() + 1 > self.capacity() {
+			return Err(element);
 		}
 
 		unsafe {
-			Some(self.swap_remove_unchecked(index))
+			// safety: just confirmed index is in bounds and there is enough capacity
+			self.insert_unchecked(index, element);
 		}
+
+		Ok(())
 	}
 
-	/// remove and return an element from any index of the array,
-	/// moving the element that was previously at the end to there.
-	/// returns `None` if `index` is not `0..self.len()`.
+	/// insert an element into any index of the array, shifting
+	/// all elements after towards the end.
 	///
-	/// this is the unsafe version of this method. see [`Self::swap_remove_checked()`]
-	/// or [`Self::swap_remove()`] for safe versions.
+	/// this is the unsafe version of this method. see [`Self::insert_checked()`] or
+	/// [`Self::insert()`] for safe versions.
 	///
 	/// ## safety
 	///
-	/// - there must be at least one element in the array prior to calling
-	///   this method. ie; [`Self::len()`] `!= 0`
-	/// - `index` `<` [`Self::len()`]
+	/// - there must be enough capacity in the array for at least one more element
+	///   prior to calling this method. ie; [`Self::len()`] `<` [`Self::capacity()`].
+	/// - `index` `<=` [`Self::len()`]
 	///
 	/// ## examples
 	///
 	/// ```
 	/// # use nyarray::array;
-	/// let mut array = array![1, 2, 3, 4 => 4];
+	/// let mut array = array![=> 4];
 	///
 	/// unsafe {
-	///     // safety: array has 4 elements.
-	///     assert_eq!(array.swap_remove_unchecked(0), 1);
-	///     assert_eq!(array.swap_remove_unchecked(0), 4);
-	///     assert_eq!(array.swap_remove_unchecked(0), 3);
-	///     assert_eq!(array.swap_remove_unchecked(0), 2);
-	///     // array.swap_remove_unchecked(0) // UB
+	///     // safety: array has capacity of 4 elements.
+	///     array.insert_unchecked(0, 1);
+	///     array.insert_unchecked(0, 2);
+	///     array.insert_unchecked(0, 3);
+	///     array.insert_unchecked(0, 4);
+	///     // array.insert_unchecked(0, 5); // UB
 	/// }
 	///
-	/// assert!(array.is_empty());
+	/// assert_eq!(array, [4, 3, 2, 1]);
 	/// ```
 	#[inline]
 	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
-	pub const unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
+	pub const unsafe fn insert_unchecked(&mut self, index: usize, element: T) {
 		unsafe {
 			let len = self.len();
 
-			let ptr = self.as_mut_ptr();
-
-			// safety: caller ensures index is in bounds and there is at least one element
-			let old = core::ptr::read(ptr.add(index));
+			let ptr = self.as_mut_ptr().add(index);
 
-			core::ptr::copy(ptr.add(len - 1), ptr.add(index), 1);
+			if index != len {
+				core::ptr::copy(ptr, ptr.add(1), len - index);
+			}
 
-			self.set_len(len - 1);
+			core::ptr::write(ptr, element);
 
-			old
+			self.set_len(len + 1);
 		}
 	}
-}
-
-impl<const N: usize, T> Drop for Array<N, T> {
-	fn drop(&mut self) {
-		self.clear();
-	}
-}
 
-impl<const N: usize, T> Default for Array<N, T> {
-	fn default() -> Self {
-		Self::new()
-	}
-}
+	/// clone every element of `values` into the array at `index`, shifting
+	/// all elements after towards the end.
+	///
+	/// the tail is moved only once, so this is faster than calling
+	/// [`Self::insert()`] for each element.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	///
+	/// array.insert_slice(1, &[10, 20]);
+	/// assert_eq!(array, [1, 10, 20, 2, 3]);
+	///
+	/// array.insert_slice(5, &[30]);
+	/// assert_eq!(array, [1, 10, 20, 2, 3, 30]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if there isn't enough space for every element of
+	/// `values`, or if `index` is greater than [`Self::len()`].
+	/// for a non-panicking version, see [`Self::try_insert_slice()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.insert_slice(0, &[4, 5]); // panics
+	/// ```
+	pub fn insert_slice(&mut self, index: usize, values: &[T])
+	where
+		T: Clone,
+	{
+		if index > self.len() {
+			panic!("index out of bounds");
+		}
 
-impl<const N: usize, T: Clone> Clone for Array<N, T> {
-	fn clone(&self) -> Self {
-		self.iter().cloned().collect()
+		if self.try_insert_slice(index, values).is_err() {
+			panic!("insert exceeds capacity");
+		}
 	}
-}
 
-impl<const N: usize, T> AsRef<[T]> for Array<N, T> {
-	fn as_ref(&self) -> &[T] {
-		self.as_slice()
-	}
-}
+	/// clone every element of `values` into the array at `index`, shifting
+	/// all elements after towards the end. returns `Err(())` if there isn't
+	/// enough space, or if `index` is greater than [`Self::len()`], in which
+	/// case the array is left untouched.
+	///
+	/// if a clone panics, the elements cloned so far stay in the array, and
+	/// nothing is leaked.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	///
+	/// assert_eq!(array.try_insert_slice(0, &[3, 4]), Ok(()));
+	/// assert_eq!(array, [3, 4, 1, 2]);
+	///
+	/// assert_eq!(array.try_insert_slice(0, &[5]), Err(()));
+	/// assert_eq!(array, [3, 4, 1, 2]);
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the failures are running out of capacity, or a bad index")]
+	pub fn try_insert_slice(&mut self, index: usize, values: &[T]) -> Result<(), ()>
+	where
+		T: Clone,
+	{
+		// closes the gap left by any elements that weren't cloned, even if a
+		// clone panics
+		struct Guard<'a, const N: usize, T> {
+			array: &'a mut Array<N, T>,
+			index: usize,
+			count: usize,
+			written: usize,
+			tail_len: usize,
+		}
 
-impl<const N: usize, T> AsMut<[T]> for Array<N, T> {
-	fn as_mut(&mut self) -> &mut [T] {
-		self.as_mut_slice()
-	}
-}
+		impl<const N: usize, T> Drop for Guard<'_, N, T> {
+			fn drop(&mut self) {
+				let start = self.index + self.written;
+
+				unsafe {
+					// safety: the tail is initialized, and `start..` is free
+					if self.written != self.count {
+						let ptr = self.array.as_mut_ptr();
+						core::ptr::copy(ptr.add(self.index + self.count), ptr.add(start), self.tail_len);
+					}
+					self.array.set_len(start + self.tail_len);
+				}
+			}
+		}
+
+		let len = self.len();
+		let count = values.len();
+
+		if index > len || count > self.capacity() - len {
+			return Err(());
+		}
+
+		unsafe {
+			// safety: just confirmed there is room for the tail to move up by
+			// `count`. the tail is owned by the guard until it is dropped
+			let ptr = self.as_mut_ptr().add(index);
+			core::ptr::copy(ptr, ptr.add(count), len - index);
+			self.set_len(index);
+		}
+
+		let mut guard = Guard {
+			array: self,
+			index,
+			count,
+			written: 0,
+			tail_len: len - index,
+		};
+
+		for value in values {
+			unsafe {
+				// safety: `index + written` is inside the gap
+				let ptr = guard.array.as_mut_ptr().add(guard.index + guard.written);
+				core::ptr::write(ptr, value.clone());
+			}
+			guard.written += 1;
+		}
+
+		Ok(())
+	}
+
+	/// insert an element into any index of the array, moving the element
+	/// that was previously there to the end.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 6];
+	///
+	/// array.swap_insert(2, 10);
+	/// assert_eq!(array, [1, 2, 10, 3]);
+	///
+	/// array.swap_insert(0, 20);
+	/// assert_eq!(array, [20, 2, 10, 3, 1]);
+	///
+	/// array.swap_insert(5, 30);
+	/// assert_eq!(array, [20, 2, 10, 3, 1, 30]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if there isn't enough space for another element,
+	/// or if `index` is not `0..=self.len()`.
+	/// for a non-panicking version, see [`Self::swap_insert_checked()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.swap_insert(0, 4); // okay
+	/// array.swap_insert(0, 5); // panics
+	/// ```
+	#[inline]
+	pub const fn swap_insert(&mut self, index: usize, element: T) {
+		if index > self.len() {
+			panic!("index out of bounds");
+		}
+
+		if self.len() + 1 > self.capacity() {
+			panic!("insert exceeds capacity");
+		}
+
+		unsafe {
+			// safety: just confirmed index is in bounds and there is enough capacity
+			self.swap_insert_unchecked(index, element);
+		}
+		// todo: edit when const Drop
+		/*
+		if self.swap_insert_checked(index, element).is_err() {
+			if index > self.len() {
+				panic!("index out of bounds");
+			} else {
+				panic!("insert exceeds capacity");
+			}
+		}
+		*/
+	}
+
+	/// insert an element into any index of the array, moving the element
+	/// that was previously there to the end. returns Err(T) if there
+	/// is not enough capacity, or if `index` is not `0..=self.len()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # fn main() -> Result<(), i32> {
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 6];
+	///
+	/// array.swap_insert_checked(2, 10)?;
+	/// assert_eq!(array, [1, 2, 10, 3]);
+	///
+	/// array.swap_insert_checked(0, 20)?;
+	/// assert_eq!(array, [20, 2, 10, 3, 1]);
+	///
+	/// array.swap_insert_checked(5, 30)?;
+	/// assert_eq!(array, [20, 2, 10, 3, 1, 30]);
+	/// # Ok(())
+	/// # }
+	/// ```
+	#[inline]
+	pub const fn swap_insert_checked(&mut self, index: usize, element: T) -> Result<(), T> {
+		if index > self.len() {
+			return Err(element);
+		}
+
+		if self.len() + 1 > self.capacity() {
+			return Err(element);
+		}
+
+		unsafe {
+			// safety: just confirmed index is in bounds and there is enough capacity
+			self.swap_insert_unchecked(index, element);
+		}
+
+		Ok(())
+	}
+
+	/// insert an element into any index of the array, moving the element
+	/// that was previously there to the end.
+	///
+	/// this is the unsafe version of this method. see [`Self::swap_insert_checked()`]
+	/// or [`Self::swap_insert()`] for safe versions.
+	///
+	/// ## safety
+	///
+	/// - there must be enough capacity in the array for at least one more element
+	///   prior to calling this method. ie; [`Self::len()`] `<` [`Self::capacity()`].
+	/// - `index` `<=` [`Self::len()`]
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![=> 4];
+	///
+	/// unsafe {
+	///     // safety: array has a capacity of 4
+	///     array.swap_insert_unchecked(0, 1);
+	///     array.swap_insert_unchecked(0, 2);
+	///     array.swap_insert_unchecked(0, 3);
+	///     array.swap_insert_unchecked(0, 4);
+	///     // array.swap_insert_unchecked(0, 5); // UB
+	/// }
+	///
+	/// assert_eq!(array, [4, 1, 2, 3])
+	/// ```
+	#[inline]
+	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
+	pub const unsafe fn swap_insert_unchecked(&mut self, index: usize, element: T) {
+		unsafe {
+			let len = self.len();
+
+			let ptr = self.as_mut_ptr();
+
+			// safety: caller ensures `index` is in bounds and there is enough
+			// space for another element.
+			let old_ptr = ptr.add(index);
+			let new_ptr = ptr.add(len);
+
+			core::ptr::write(new_ptr, element);
+			core::ptr::swap(old_ptr, new_ptr);
+
+			self.set_len(len + 1);
+		}
+	}
+
+	/// insert an element into any index of the array, moving the element
+	/// that was previously there to the end. returns the index the displaced
+	/// element was moved to, or `Err(element)` if there is not enough capacity,
+	/// or if `index` is not `0..=self.len()`.
+	///
+	/// the returned index is always [`Self::len()`] prior to inserting. if
+	/// `index` was [`Self::len()`], nothing was displaced, and the returned
+	/// index holds the new element.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 6];
+	///
+	/// let moved = array.swap_insert_returning(0, 10).unwrap();
+	/// assert_eq!(moved, 3);
+	/// assert_eq!(array[moved], 1);
+	/// assert_eq!(array, [10, 2, 3, 1]);
+	///
+	/// assert_eq!(array.swap_insert_returning(5, 20), Err(20));
+	/// ```
+	#[inline]
+	pub const fn swap_insert_returning(&mut self, index: usize, element: T) -> Result<usize, T> {
+		let len = self.len();
+
+		if index > len {
+			return Err(element);
+		}
+
+		if len + 1 > self.capacity() {
+			return Err(element);
+		}
+
+		unsafe {
+			// safety: just confirmed index is in bounds and there is enough capacity
+			self.swap_insert_unchecked(index, element);
+		}
+
+		Ok(len)
+	}
+
+	/// remove and return an element out of any index of the array,
+	/// shifting all elements after towards the start.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
+	///
+	/// assert_eq!(array.remove(0), 1);
+	/// assert_eq!(array, [2, 3, 4, 5, 6]);
+	///
+	/// assert_eq!(array.remove(2), 4);
+	/// assert_eq!(array, [2, 3, 5, 6]);
+	///
+	/// assert_eq!(array.remove(3), 6);
+	/// assert_eq!(array, [2, 3, 5]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `index` is not `0..self.len()`.
+	/// for a non-panicking version, see [`Self::remove_checked()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4 => 4];
+	/// array.remove(4); // panics
+	/// ```
+	#[inline]
+	pub const fn remove(&mut self, index: usize) -> T {
+		if index >= self.len() {
+			panic!("index out of bounds");
+		}
+
+		unsafe {
+			self.remove_unchecked(index)
+		}
+		// todo: edit when const Drop
+		/*
+		match self.remove_checked(index) {
+			Some(x) => x,
+			None => panic!("index out of bounds"),
+		}
+		*/
+	}
+
+	/// remove and return an element out of any index of the array,
+	/// shifting all elements after towards the start. returns `None`
+	/// if `index` is not `0..self.len()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
+	///
+	/// assert_eq!(array.remove_checked(0), Some(1));
+	/// assert_eq!(array, [2, 3, 4, 5, 6]);
+	///
+	/// assert_eq!(array.remove_checked(2), Some(4));
+	/// assert_eq!(array, [2, 3, 5, 6]);
+	///
+	/// assert_eq!(array.remove_checked(3), Some(6));
+	/// assert_eq!(array, [2, 3, 5]);
+	///
+	/// assert_eq!(array.remove_checked(3), None);
+	/// assert_eq!(array, [2, 3, 5]);
+	/// ```
+	#[inline]
+	pub const fn remove_checked(&mut self, index: usize) -> Option<T> {
+		if index >= self.len() {
+			return None;
+		}
+
+		unsafe {
+			// safety: just confirmed index is in bounds
+			Some(self.remove_unchecked(index))
+		}
+	}
+
+	/// remove and return an element out of any index of the array,
+	/// shifting all elements after towards the start.
+	///
+	/// this is the unsafe version of this method. see [`Self::remove_checked()`]
+	/// or [`Self::remove()`] for safe versions.
+	///
+	/// ## safety
+	///
+	/// - there must be at least one element in the array prior to calling
+	///   this method. ie; [`Self::len()`] `!= 0`
+	/// - `index` `<` [`Self::len()`]
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4 => 4];
+	///
+	/// unsafe {
+	///     // safety: array has 4 elements.
+	///     assert_eq!(array.remove_unchecked(0), 1);
+	///     assert_eq!(array.remove_unchecked(0), 2);
+	///     assert_eq!(array.remove_unchecked(0), 3);
+	///     assert_eq!(array.remove_unchecked(0), 4);
+	///     // array.remove_unchecked(0) // UB
+	/// }
+	///
+	/// assert!(array.is_empty());
+	/// ```
+	#[inline]
+	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
+	pub const unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+		unsafe {
+			let len = self.len();
+
+			// safety: caller ensures index is in bounds and there is at least one element
+			let ptr = self.as_mut_ptr().add(index);
+
+			let old = core::ptr::read(ptr);
+
+			core::ptr::copy(ptr.add(1), ptr, len - index - 1);
+
+			self.set_len(len - 1);
+
+			old
+		}
+	}
+
+	/// remove and return the first element of the array, shifting all
+	/// elements after towards the start. returns `None` if the array is empty.
+	///
+	/// this is the same as `array.remove_checked(0)`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	///
+	/// assert_eq!(array.remove_first(), Some(1));
+	/// assert_eq!(array, [2, 3]);
+	///
+	/// array.clear();
+	/// assert_eq!(array.remove_first(), None);
+	/// ```
+	#[inline]
+	pub const fn remove_first(&mut self) -> Option<T> {
+		self.remove_checked(0)
+	}
+
+	/// remove and return the last element of the array. returns `None` if
+	/// the array is empty.
+	///
+	/// this is the same as [`Self::pop()`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	///
+	/// assert_eq!(array.remove_last(), Some(3));
+	/// assert_eq!(array, [1, 2]);
+	///
+	/// array.clear();
+	/// assert_eq!(array.remove_last(), None);
+	/// ```
+	#[inline]
+	pub const fn remove_last(&mut self) -> Option<T> {
+		self.pop()
+	}
+
+	/// remove and return an element from any index of the array,
+	/// moving the element that was previously at the end to there.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
+	///
+	/// assert_eq!(array.swap_remove(0), 1);
+	/// assert_eq!(array, [6, 2, 3, 4, 5]);
+	///
+	/// assert_eq!(array.swap_remove(2), 3);
+	/// assert_eq!(array, [6, 2, 5, 4]);
+	///
+	/// assert_eq!(array.swap_remove(3), 4);
+	/// assert_eq!(array, [6, 2, 5]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `index` is not `0..=self.len()`.
+	/// for a non-panicking version, see [`Self::swap_remove_checked()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4 => 4];
+	/// array.swap_remove(4); // panics
+	/// ```
+	#[inline]
+	pub const fn swap_remove(&mut self, index: usize) -> T {
+		if index >= self.len() {
+			panic!("index out of bounds");
+		}
+
+		unsafe {
+			self.swap_remove_unchecked(index)
+		}
+
+		// todo: edit when const Drop
+	}
+
+	/// remove and return an element from any index of the array,
+	/// moving the element that was previously at the end to there.
+	/// returns `None` if `index` is not `0..self.len()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
+	///
+	/// assert_eq!(array.swap_remove_checked(0), Some(1));
+	/// assert_eq!(array, [6, 2, 3, 4, 5]);
+	///
+	/// assert_eq!(array.swap_remove_checked(2), Some(3));
+	/// assert_eq!(array, [6, 2, 5, 4]);
+	///
+	/// assert_eq!(array.swap_remove_checked(3), Some(4));
+	/// assert_eq!(array, [6, 2, 5]);
+	///
+	/// assert_eq!(array.swap_remove_checked(3), None);
+	/// assert_eq!(array, [6, 2, 5]);
+	/// ```
+	#[inline]
+	pub const fn swap_remove_checked(&mut self, index: usize) -> Option<T> {
+		if index >= self.len() {
+			return None;
+		}
+
+		unsafe {
+			Some(self.swap_remove_unchecked(index))
+		}
+	}
+
+	/// remove and return an element from any index of the array,
+	/// moving the element that was previously at the end to there.
+	/// returns `None` if `index` is not `0..self.len()`.
+	///
+	/// this is the unsafe version of this method. see [`Self::swap_remove_checked()`]
+	/// or [`Self::swap_remove()`] for safe versions.
+	///
+	/// ## safety
+	///
+	/// - there must be at least one element in the array prior to calling
+	///   this method. ie; [`Self::len()`] `!= 0`
+	/// - `index` `<` [`Self::len()`]
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4 => 4];
+	///
+	/// unsafe {
+	///     // safety: array has 4 elements.
+	///     assert_eq!(array.swap_remove_unchecked(0), 1);
+	///     assert_eq!(array.swap_remove_unchecked(0), 4);
+	///     assert_eq!(array.swap_remove_unchecked(0), 3);
+	///     assert_eq!(array.swap_remove_unchecked(0), 2);
+	///     // array.swap_remove_unchecked(0) // UB
+	/// }
+	///
+	/// assert!(array.is_empty());
+	/// ```
+	#[inline]
+	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
+	pub const unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
+		unsafe {
+			let len = self.len();
+
+			let ptr = self.as_mut_ptr();
+
+			// safety: caller ensures index is in bounds and there is at least one element
+			let old = core::ptr::read(ptr.add(index));
+
+			core::ptr::copy(ptr.add(len - 1), ptr.add(index), 1);
+
+			self.set_len(len - 1);
+
+			old
+		}
+	}
+
+	/// swap the element at `index` with the last element, then pop it off
+	/// the end of the array. returns `None` if `index` is not `0..self.len()`.
+	///
+	/// this is the same operation as [`Self::swap_remove_checked()`]: the
+	/// element at `index` is returned, and the element that was last moves
+	/// into its place. no other elements move.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 6];
+	///
+	/// assert_eq!(array.swap_pop(0), Some(1));
+	/// assert_eq!(array, [6, 2, 3, 4, 5]);
+	///
+	/// assert_eq!(array.swap_pop(2), Some(3));
+	/// assert_eq!(array, [6, 2, 5, 4]);
+	///
+	/// assert_eq!(array.swap_pop(3), Some(4));
+	/// assert_eq!(array, [6, 2, 5]);
+	///
+	/// assert_eq!(array.swap_pop(3), None);
+	/// assert_eq!(array, [6, 2, 5]);
+	/// ```
+	#[inline]
+	pub const fn swap_pop(&mut self, index: usize) -> Option<T> {
+		self.swap_remove_checked(index)
+	}
+
+	/// like [`Self::swap_remove_checked()`], but also returns whether the last
+	/// element was moved into `index`. this is `false` when `index` was the
+	/// last element, so nothing had to move.
+	///
+	/// returns `None` if `index` is not `0..self.len()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4 => 4];
+	///
+	/// assert_eq!(array.swap_remove_returning(3), Some((4, false)));
+	/// assert_eq!(array, [1, 2, 3]);
+	///
+	/// assert_eq!(array.swap_remove_returning(0), Some((1, true)));
+	/// assert_eq!(array, [3, 2]);
+	///
+	/// assert_eq!(array.swap_remove_returning(2), None);
+	/// ```
+	#[inline]
+	pub const fn swap_remove_returning(&mut self, index: usize) -> Option<(T, bool)> {
+		if index >= self.len() {
+			return None;
+		}
+
+		let moved = index != self.len() - 1;
+
+		unsafe {
+			Some((self.swap_remove_unchecked(index), moved))
+		}
+	}
+
+	/// removes consecutive equal elements, keeping the first of each run.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 1, 2, 3, 3, 3 => 8];
+	///
+	/// array.dedup();
+	/// assert_eq!(array, [1, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn dedup(&mut self)
+	where
+		T: PartialEq,
+	{
+		self.dedup_by(|a, b| a == b);
+	}
+
+	/// removes consecutive elements that `same_bucket` considers equal,
+	/// keeping the first of each run.
+	///
+	/// `same_bucket` is called with the element being checked, and the
+	/// previous element that was kept. if it returns `true`, the element is
+	/// removed.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 1, 2, 3, 3, 3 => 8];
+	///
+	/// array.dedup_by(|a, b| a == b);
+	/// assert_eq!(array, [1, 2, 3]);
+	///
+	/// let mut array = array![1u32, 2, 4, 5, 8, 9 => 8];
+	///
+	/// // merge runs of numbers that are close together
+	/// array.dedup_by(|a, b| a.abs_diff(*b) <= 1);
+	/// assert_eq!(array, [1, 4, 8]);
+	/// ```
+	#[inline]
+	pub fn dedup_by<F: FnMut(&mut T, &mut T) -> bool>(&mut self, mut same_bucket: F) {
+		self.retain_with(|kept, element| {
+			core::ops::ControlFlow::Continue(match kept.last_mut() {
+				Some(last) => !same_bucket(element, last),
+				None => true,
+			})
+		});
+	}
+
+	/// removes consecutive elements that resolve to the same key, keeping
+	/// the first of each run.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 1, 2, 3, 3, 3 => 8];
+	///
+	/// array.dedup_by_key(|x| *x);
+	/// assert_eq!(array, [1, 2, 3]);
+	///
+	/// let mut array = array![10, 11, 20, 30, 31, 32 => 8];
+	///
+	/// array.dedup_by_key(|x| *x / 10);
+	/// assert_eq!(array, [10, 20, 30]);
+	/// ```
+	#[inline]
+	pub fn dedup_by_key<K: PartialEq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+		self.dedup_by(|a, b| key(a) == key(b));
+	}
+
+	/// removes consecutive elements that resolve to the same key, keeping
+	/// the first of each run.
+	///
+	/// unlike a by-value key, `key` returns a reference, allowing a borrowed
+	/// field to be compared without cloning it.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// # use nyarray::array::Array;
+	/// struct Item {
+	///     name: String,
+	///     value: u32,
+	/// }
+	///
+	/// let mut array: Array<8, _> = array![
+	///     Item { name: "a".into(), value: 0 },
+	///     Item { name: "a".into(), value: 1 },
+	///     Item { name: "b".into(), value: 2 },
+	///     Item { name: "a".into(), value: 3 },
+	///     Item { name: "a".into(), value: 4 },
+	/// ];
+	///
+	/// array.dedup_by_ref_key(|item| item.name.as_str());
+	///
+	/// assert_eq!(array.iter().map(|item| item.value).collect::<Array<8, _>>(), [0, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn dedup_by_ref_key<K: PartialEq + ?Sized, F: FnMut(&T) -> &K>(&mut self, mut key: F) {
+		self.retain_with(|kept, element| {
+			core::ops::ControlFlow::Continue(match kept.last() {
+				Some(last) => key(last) != key(element),
+				None => true,
+			})
+		});
+	}
+
+	/// resize the array to `new_len`, returning whatever elements were removed.
+	///
+	/// if `new_len` is greater than [`Self::len()`], the array is extended with
+	/// clones of `value`, and an empty array is returned. if `new_len` is lesser,
+	/// the elements `new_len..` are moved into the returned array rather than
+	/// being dropped.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// let tail = array.resize_returning(2, 0);
+	/// assert_eq!(array, [1, 2]);
+	/// assert_eq!(tail, [3, 4, 5]);
+	///
+	/// let tail = array.resize_returning(4, 0);
+	/// assert_eq!(array, [1, 2, 0, 0]);
+	/// assert!(tail.is_empty());
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `new_len` is greater than [`Self::capacity()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.resize_returning(5, 0); // panics
+	/// ```
+	pub fn resize_returning(&mut self, new_len: usize, value: T) -> Array<N, T>
+	where
+		T: Clone,
+	{
+		let len = self.len();
+
+		if new_len < len {
+			unsafe {
+				// safety: just confirmed `new_len` is in bounds
+				self.split_off_unchecked(new_len)
+			}
+		} else {
+			self.resize(new_len, value);
+			Array::new()
+		}
+	}
+
+	/// shortens the array to `len` elements, dropping the rest.
+	/// if `len` is greater or equal to [`Self::len()`], this does nothing.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// array.truncate(2);
+	/// assert_eq!(array, [1, 2]);
+	///
+	/// array.truncate(4);
+	/// assert_eq!(array, [1, 2]);
+	/// ```
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		let old_len = self.len();
+
+		if len >= old_len {
+			return;
+		}
+
+		unsafe {
+			// set len first, so a panicking destructor can't cause a double drop
+			self.set_len(len);
+
+			let tail = core::ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), old_len - len);
+			core::ptr::drop_in_place(tail);
+		}
+	}
+
+	/// splits the array in two at `at`. `self` keeps `0..at`, and the returned
+	/// array holds the elements moved out from `at..len`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// let tail = array.split_off(2);
+	/// assert_eq!(array, [1, 2]);
+	/// assert_eq!(tail, [3, 4, 5]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `at` is greater than [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.split_off(4); // panics
+	/// ```
+	pub fn split_off(&mut self, at: usize) -> Array<N, T> {
+		let len = self.len();
+
+		if at > len {
+			panic!("index out of bounds");
+		}
+
+		unsafe {
+			// safety: just confirmed `at` is in bounds
+			self.split_off_unchecked(at)
+		}
+	}
+
+	/// moves the elements `at..` into a new array, shared by the methods
+	/// that split the array in two.
+	///
+	/// ## safety
+	///
+	/// `at` `<=` [`Self::len()`]
+	unsafe fn split_off_unchecked(&mut self, at: usize) -> Array<N, T> {
+		let len = self.len();
+		let mut tail = Array::new();
+
+		unsafe {
+			// safety: `at..len` is initialized, and fits in `tail` as both arrays
+			// share the same capacity. the moved elements are forgotten by `self`.
+			core::ptr::copy_nonoverlapping(
+				self.as_ptr().add(at),
+				tail.as_mut_ptr(),
+				len - at,
+			);
+
+			self.set_len(at);
+			tail.set_len(len - at);
+		}
+
+		tail
+	}
+
+	/// moves every element of `other` onto the end of the array, leaving
+	/// `other` empty. `other` can have a different capacity.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 8];
+	/// let mut other = array![3, 4, 5 => 3];
+	///
+	/// array.append(&mut other);
+	/// assert_eq!(array, [1, 2, 3, 4, 5]);
+	/// assert!(other.is_empty());
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if the elements of `other` don't fit in the
+	/// remaining capacity. see [`Self::try_append()`] for a non-panicking
+	/// version.
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	/// let mut other = array![3, 4, 5 => 4];
+	/// array.append(&mut other); // panics
+	/// ```
+	#[inline]
+	pub fn append<const M: usize>(&mut self, other: &mut Array<M, T>) {
+		if self.try_append(other).is_err() {
+			panic!("append exceeds capacity");
+		}
+	}
+
+	/// moves every element of `other` onto the end of the array, leaving
+	/// `other` empty. `other` can have a different capacity.
+	///
+	/// if the elements of `other` don't fit in the remaining capacity,
+	/// returns `Err(())` and neither array is modified.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	///
+	/// let mut other = array![3, 4, 5 => 4];
+	/// assert_eq!(array.try_append(&mut other), Err(()));
+	/// assert_eq!(other, [3, 4, 5]);
+	///
+	/// let mut other = array![3, 4 => 4];
+	/// assert_eq!(array.try_append(&mut other), Ok(()));
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	/// assert!(other.is_empty());
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn try_append<const M: usize>(&mut self, other: &mut Array<M, T>) -> Result<(), ()> {
+		let len = self.len();
+		let count = other.len();
+
+		if count > N - len {
+			return Err(());
+		}
+
+		unsafe {
+			// safety: `other` is initialized for `count` elements, and there is
+			// room for them after `len`. `other` forgets the moved elements.
+			core::ptr::copy_nonoverlapping(
+				other.as_ptr(),
+				self.as_mut_ptr().add(len),
+				count,
+			);
+
+			other.set_len(0);
+			self.set_len(len + count);
+		}
+
+		Ok(())
+	}
+
+	/// removes the elements in `range` from the array, returning them in an
+	/// iterator. the elements after `range` are shifted down once the iterator
+	/// is dropped.
+	///
+	/// the removed elements are dropped along with the iterator, even if they
+	/// weren't iterated. if the iterator is leaked, (ie; [`core::mem::forget()`]),
+	/// the array may lose the drained elements along with its tail.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// # use nyarray::array::Array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// let drained: Array<8, i32> = array.drain(1..3).collect();
+	/// assert_eq!(drained, [2, 3]);
+	/// assert_eq!(array, [1, 4, 5]);
+	///
+	/// array.drain(..);
+	/// assert!(array.is_empty());
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `range` is reversed, or goes past [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.drain(1..4); // panics
+	/// ```
+	pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, N, T> {
+		let len = self.len();
+
+		let Some(range) = resolve_range(range, len) else {
+			panic!("range out of bounds");
+		};
+
+		unsafe {
+			// safety: everything after `range.start` is now owned by the `Drain`,
+			// so nothing can be observed twice if it is leaked
+			self.set_len(range.start);
+		}
+
+		Drain {
+			array: self,
+			cur: range.start,
+			end: range.end,
+			tail: range.end,
+			tail_len: len - range.end,
+		}
+	}
+
+	/// drops the elements in `range`, shifting the elements after it down to
+	/// fill the gap. this is the same as dropping [`Self::drain()`].
+	///
+	/// if dropping an element panics, the rest are still dropped, and the
+	/// tail is still shifted down.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// array.remove_range(1..3);
+	/// assert_eq!(array, [1, 4, 5]);
+	///
+	/// array.remove_range(1..);
+	/// assert_eq!(array, [1]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `range` is reversed, or goes past [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.remove_range(2..4); // panics
+	/// ```
+	#[inline]
+	pub fn remove_range<R: core::ops::RangeBounds<usize>>(&mut self, range: R) {
+		self.drain(range);
+	}
+
+	/// resize the array to `new_len`.
+	///
+	/// if `new_len` is greater than [`Self::len()`], the array is extended with
+	/// clones of `value`. if `new_len` is lesser, the array is truncated.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 6];
+	///
+	/// array.resize(5, 0);
+	/// assert_eq!(array, [1, 2, 3, 0, 0]);
+	///
+	/// array.resize(2, 0);
+	/// assert_eq!(array, [1, 2]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `new_len` is greater than [`Self::capacity()`].
+	/// for a non-panicking version, see [`Self::resize_checked()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.resize(5, 0); // panics
+	/// ```
+	#[inline]
+	pub fn resize(&mut self, new_len: usize, value: T)
+	where
+		T: Clone,
+	{
+		if self.resize_checked(new_len, value).is_err() {
+			panic!("resize exceeds capacity");
+		}
+	}
+
+	/// resize the array to `new_len`. returns `Err(value)` if `new_len` is
+	/// greater than [`Self::capacity()`], leaving the array untouched.
+	///
+	/// if `new_len` is greater than [`Self::len()`], the array is extended with
+	/// clones of `value`. if `new_len` is lesser, the array is truncated.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	///
+	/// assert_eq!(array.resize_checked(4, 0), Ok(()));
+	/// assert_eq!(array, [1, 2, 3, 0]);
+	///
+	/// assert_eq!(array.resize_checked(5, 0), Err(0));
+	/// assert_eq!(array, [1, 2, 3, 0]);
+	/// ```
+	pub fn resize_checked(&mut self, new_len: usize, value: T) -> Result<(), T>
+	where
+		T: Clone,
+	{
+		let len = self.len();
+
+		if new_len > self.capacity() {
+			return Err(value);
+		}
+
+		if new_len <= len {
+			self.truncate(new_len);
+			return Ok(());
+		}
+
+		for _ in len + 1..new_len {
+			unsafe {
+				// safety: just confirmed there is enough capacity
+				self.push_unchecked(value.clone());
+			}
+		}
+
+		unsafe {
+			self.push_unchecked(value);
+		}
+
+		Ok(())
+	}
+
+	/// resize the array to `new_len`.
+	///
+	/// if `new_len` is greater than [`Self::len()`], the array is extended with
+	/// values produced by calling `f`. if `new_len` is lesser, the array is truncated.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 6];
+	///
+	/// let mut n = 3;
+	/// array.resize_with(5, || { n += 1; n });
+	/// assert_eq!(array, [1, 2, 3, 4, 5]);
+	///
+	/// array.resize_with(2, || unreachable!());
+	/// assert_eq!(array, [1, 2]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `new_len` is greater than [`Self::capacity()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	/// array.resize_with(5, || 0); // panics
+	/// ```
+	pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, mut f: F) {
+		if new_len > self.capacity() {
+			panic!("resize exceeds capacity");
+		}
+
+		if new_len <= self.len() {
+			self.truncate(new_len);
+			return;
+		}
+
+		while self.len() < new_len {
+			unsafe {
+				// safety: just confirmed there is enough capacity
+				self.push_unchecked(f());
+			}
+		}
+	}
+
+	/// insert an element into its sorted position, unless an equal element
+	/// is already present. the array is assumed to be sorted.
+	///
+	/// returns `Ok(index)` with the position the element was inserted at.
+	/// if an equal element exists, returns `Err((index, value))` with the index
+	/// of that element. if the array is full, returns `Err((self.len(), value))`;
+	/// since an existing element's index is always lesser than [`Self::len()`],
+	/// the two can be told apart.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 3, 5 => 4];
+	///
+	/// assert_eq!(array.binary_insert_unique(4), Ok(2));
+	/// assert_eq!(array, [1, 3, 4, 5]);
+	///
+	/// assert_eq!(array.binary_insert_unique(3), Err((1, 3)));
+	///
+	/// // full
+	/// assert_eq!(array.binary_insert_unique(2), Err((4, 2)));
+	/// assert_eq!(array, [1, 3, 4, 5]);
+	/// ```
+	pub fn binary_insert_unique(&mut self, value: T) -> Result<usize, (usize, T)>
+	where
+		T: Ord,
+	{
+		match self.as_slice().binary_search(&value) {
+			Ok(index) => Err((index, value)),
+			Err(_) if self.len() == self.capacity() => Err((self.len(), value)),
+			Err(index) => {
+				unsafe {
+					// safety: `index <= len`, and just confirmed there is enough capacity
+					self.insert_unchecked(index, value);
+				}
+				Ok(index)
+			}
+		}
+	}
+
+	/// returns a new array of running accumulations, where each element `i`
+	/// is `f` folded over the elements `0..=i`. the first element is a clone
+	/// of this array's first element.
+	///
+	/// `f` is given the previous accumulation and the current element.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3, 4, 5 => 5];
+	///
+	/// let sums = array.prefix_scan(|acc, x| acc + x);
+	/// assert_eq!(sums, [1, 3, 6, 10, 15]);
+	///
+	/// let products = array.prefix_scan(|acc, x| acc * x);
+	/// assert_eq!(products, [1, 2, 6, 24, 120]);
+	/// ```
+	pub fn prefix_scan<F: FnMut(&T, &T) -> T>(&self, mut f: F) -> Array<N, T>
+	where
+		T: Clone,
+	{
+		let mut out = Array::new();
+
+		let mut iter = self.iter();
+
+		if let Some(first) = iter.next() {
+			unsafe {
+				// safety: `out` has the same capacity as `self`, which isn't empty
+				out.push_unchecked(first.clone());
+			}
+
+			for element in iter {
+				let next = f(&out[out.len() - 1], element);
+				unsafe {
+					// safety: `out` never holds more elements than `self`
+					out.push_unchecked(next);
+				}
+			}
+		}
+
+		out
+	}
+
+	/// transmute the initialized elements to another type, ensuring alignment
+	/// of the types is maintained. forwards to `slice::align_to`.
+	///
+	/// returns a prefix, a middle slice of `U`, and a suffix. the prefix and
+	/// suffix hold the elements that could not be aligned to `U`.
+	///
+	/// ## safety
+	///
+	/// this is as unsafe as `slice::align_to`, and its requirements apply here:
+	/// the elements of the middle slice must be valid values of `U`. this is
+	/// generally fine for plain integer types.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array: Array<32, u8> = (0..32).collect();
+	///
+	/// let (prefix, lanes, suffix) = unsafe { array.align_to::<u32>() };
+	///
+	/// assert_eq!(prefix.len() + lanes.len() * 4 + suffix.len(), 32);
+	/// assert!(lanes.len() >= 7);
+	/// ```
+	#[inline]
+	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
+	pub unsafe fn align_to<U>(&self) -> (&[T], &[U], &[T]) {
+		unsafe {
+			self.as_slice().align_to()
+		}
+	}
+
+	/// overwrite the element at `index`, returning the element that was
+	/// previously there. returns `Err(value)` if `index` is not `0..self.len()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 4];
+	///
+	/// assert_eq!(array.set(1, 10), Ok(2));
+	/// assert_eq!(array, [1, 10, 3]);
+	///
+	/// assert_eq!(array.set(3, 20), Err(20));
+	/// assert_eq!(array, [1, 10, 3]);
+	/// ```
+	#[inline]
+	pub const fn set(&mut self, index: usize, value: T) -> Result<T, T> {
+		if index >= self.len() {
+			return Err(value);
+		}
+
+		Ok(core::mem::replace(&mut self.as_mut_slice()[index], value))
+	}
+
+	/// returns the index of the largest element, or `None` if the array
+	/// is empty. if several elements are equally large, the first is returned.
+	///
+	/// elements that can't be compared (like `NaN`) are skipped over, unless
+	/// it's the first element.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1.0, 5.0, 2.0, 5.0, 3.0 => 5];
+	/// assert_eq!(array.argmax(), Some(1));
+	/// ```
+	pub fn argmax(&self) -> Option<usize>
+	where
+		T: PartialOrd,
+	{
+		let mut iter = self.iter().enumerate();
+		let (mut index, mut max) = iter.next()?;
+
+		for (i, element) in iter {
+			if element > max {
+				index = i;
+				max = element;
+			}
+		}
+
+		Some(index)
+	}
+
+	/// returns the index of the smallest element, or `None` if the array
+	/// is empty. if several elements are equally small, the first is returned.
+	///
+	/// elements that can't be compared (like `NaN`) are skipped over, unless
+	/// it's the first element.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![3.0, 1.0, 2.0, 1.0, 5.0 => 5];
+	/// assert_eq!(array.argmin(), Some(1));
+	/// ```
+	pub fn argmin(&self) -> Option<usize>
+	where
+		T: PartialOrd,
+	{
+		let mut iter = self.iter().enumerate();
+		let (mut index, mut min) = iter.next()?;
+
+		for (i, element) in iter {
+			if element < min {
+				index = i;
+				min = element;
+			}
+		}
+
+		Some(index)
+	}
+
+	/// reverse the order of elements in place, and return the array.
+	///
+	/// this is a consuming version of `slice::reverse`, meant for chaining.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3, 4 => 4];
+	/// assert_eq!(array.reversed(), [4, 3, 2, 1]);
+	/// ```
+	#[inline]
+	pub fn reversed(mut self) -> Array<N, T> {
+		self.as_mut_slice().reverse();
+		self
+	}
+
+	/// consume the array, calling `f` on every element in order, and collect
+	/// the results into a new array of the same length.
+	/// this is the `Array` version of `<[T; N]>::map`.
+	///
+	/// if `f` panics, the elements not yet mapped and the results so far are
+	/// dropped, and nothing is leaked.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 4];
+	///
+	/// let strings = array.map(|x| x.to_string());
+	/// assert_eq!(strings, ["1", "2", "3"].map(String::from));
+	/// assert_eq!(strings.capacity(), 4);
+	/// ```
+	pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> Array<N, U> {
+		let mut out = Array::new();
+
+		// the iterator owns the unmapped elements, and `out` owns the mapped
+		// ones, so both sides get dropped if `f` panics
+		for element in self {
+			unsafe {
+				// safety: `out` has the same capacity as `self`
+				out.push_unchecked(f(element));
+			}
+		}
+
+		out
+	}
+
+	/// returns a new array of `n` copies of the elements, one after another.
+	/// this is the `Array` version of [`slice::repeat()`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2 => 8];
+	///
+	/// assert_eq!(array.repeat(3), [1, 2, 1, 2, 1, 2]);
+	/// assert!(array.repeat(0).is_empty());
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `n` copies don't fit in the array capacity.
+	/// for a non-panicking version, see [`Self::repeat_checked()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let array = array![1, 2 => 8];
+	/// array.repeat(5); // panics
+	/// ```
+	#[inline]
+	pub fn repeat(&self, n: usize) -> Array<N, T>
+	where
+		T: Clone,
+	{
+		match self.repeat_checked(n) {
+			Some(array) => array,
+			None => panic!("repeat exceeds capacity"),
+		}
+	}
+
+	/// returns a new array of `n` copies of the elements, one after another,
+	/// or `None` if they don't fit in the array capacity.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2 => 8];
+	///
+	/// assert_eq!(array.repeat_checked(4), Some(array![1, 2, 1, 2, 1, 2, 1, 2]));
+	/// assert_eq!(array.repeat_checked(5), None);
+	/// assert_eq!(array.repeat_checked(usize::MAX), None);
+	/// ```
+	pub fn repeat_checked(&self, n: usize) -> Option<Array<N, T>>
+	where
+		T: Clone,
+	{
+		let mut out = Array::new();
+
+		// nothing to copy, and looping `n` times could take forever
+		if self.is_empty() || n == 0 {
+			return Some(out);
+		}
+
+		if self.len().checked_mul(n)? > N {
+			return None;
+		}
+
+		for _ in 0..n {
+			for element in self {
+				unsafe {
+					// safety: just confirmed `n` copies fit
+					out.push_unchecked(element.clone());
+				}
+			}
+		}
+
+		Some(out)
+	}
+
+	/// clone every element of `other` onto the end of the array.
+	///
+	/// capacity is checked once up front. if a clone panics, the elements
+	/// cloned so far stay in the array, and nothing is leaked.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 8];
+	/// array.extend_from_slice(&[3, 4, 5]);
+	/// assert_eq!(array, [1, 2, 3, 4, 5]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if there isn't enough space for every element of `other`.
+	/// for a non-panicking version, see [`Self::try_extend_from_slice()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	/// array.extend_from_slice(&[3, 4, 5]); // panics
+	/// ```
+	#[inline]
+	pub fn extend_from_slice(&mut self, other: &[T])
+	where
+		T: Clone,
+	{
+		if self.try_extend_from_slice(other).is_err() {
+			panic!("extend exceeds capacity");
+		}
+	}
+
+	/// clone every element of `other` onto the end of the array. returns
+	/// `Err(())` if there isn't enough space for all of them, in which case
+	/// the array is left untouched.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	///
+	/// assert_eq!(array.try_extend_from_slice(&[3, 4]), Ok(()));
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	///
+	/// assert_eq!(array.try_extend_from_slice(&[5]), Err(()));
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), ()>
+	where
+		T: Clone,
+	{
+		if other.len() > self.capacity() - self.len() {
+			return Err(());
+		}
+
+		for element in other {
+			unsafe {
+				// safety: just confirmed every element fits. the length is bumped
+				// after each clone, so a panic leaves the array consistent
+				self.push_unchecked(element.clone());
+			}
+		}
+
+		Ok(())
+	}
+
+	/// clone every element of `src` onto the end of the array.
+	///
+	/// this is the same as [`Self::extend_from_slice()`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// # use nyarray::array::Array;
+	/// let mut array: Array<4, String> = array!["a".into()];
+	/// array.extend_from_slice_cloned(&["b".into(), "c".into()]);
+	/// assert_eq!(array, ["a", "b", "c"].map(String::from));
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if there isn't enough space for every element of `src`.
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	/// array.extend_from_slice_cloned(&[3, 4, 5]); // panics
+	/// ```
+	#[inline]
+	pub fn extend_from_slice_cloned(&mut self, src: &[T])
+	where
+		T: Clone,
+	{
+		self.extend_from_slice(src);
+	}
+
+	/// remove every element at the given indices, shifting the remaining
+	/// elements towards the start, in a single pass.
+	///
+	/// `sorted_indices` must be sorted in ascending order, and every index must be
+	/// `0..self.len()`. duplicate indices are allowed, and only remove once. this is
+	/// checked with `debug_assert!`; in release, unsorted indices are not all removed,
+	/// and out of bounds indices are ignored.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![0, 1, 2, 3, 4, 5, 6, 7 => 8];
+	///
+	/// array.remove_indices(&[1, 2, 5, 5, 7]);
+	/// assert_eq!(array, [0, 3, 4, 6]);
+	/// ```
+	pub fn remove_indices(&mut self, sorted_indices: &[usize]) {
+		debug_assert!(sorted_indices.is_sorted(), "indices are not sorted");
+		debug_assert!(sorted_indices.last().is_none_or(|last| *last < self.len()), "index out of bounds");
+
+		let mut indices = sorted_indices.iter().peekable();
+		let mut index = 0;
+
+		self.retain_with(|_, _| {
+			if indices.peek().is_none() {
+				return core::ops::ControlFlow::Break(());
+			}
+
+			let mut remove = false;
+			while indices.next_if_eq(&&index).is_some() {
+				remove = true;
+			}
+
+			index += 1;
+
+			core::ops::ControlFlow::Continue(!remove)
+		});
+	}
+
+	/// retain only the elements for which `f` returns `true`, dropping the rest.
+	/// the order of the retained elements is preserved.
+	///
+	/// if `f` panics, the elements not yet visited are kept, and the array is
+	/// left in a valid state.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+	///
+	/// array.retain(|x| *x % 2 == 0);
+	/// assert_eq!(array, [2, 4, 6]);
+	/// ```
+	#[inline]
+	pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+		self.retain_with(|_, element| core::ops::ControlFlow::Continue(f(element)));
+	}
+
+	/// retain only the elements for which `f` returns `true`, dropping the rest.
+	/// unlike [`Self::retain()`], `f` is given a mutable reference to each element.
+	///
+	/// if `f` panics, the elements not yet visited are kept, and the array is
+	/// left in a valid state.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+	///
+	/// array.retain_mut(|x| {
+	///     *x *= 10;
+	///     *x > 30
+	/// });
+	/// assert_eq!(array, [40, 50, 60]);
+	/// ```
+	#[inline]
+	pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) {
+		self.retain_with(|_, element| core::ops::ControlFlow::Continue(f(element)));
+	}
+
+	/// retain only the elements for which `f` returns `Continue(true)`, until
+	/// `f` returns `Break(())`, at which point that element and every element
+	/// after it are kept untouched.
+	///
+	/// if `f` panics, the elements not yet visited are kept.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// # use core::ops::ControlFlow;
+	/// let mut array = array![1, 2, 3, 0, 4, 5, 6 => 8];
+	///
+	/// // remove odd numbers, up until the terminator
+	/// array.retain_while(|x| {
+	///     if *x == 0 {
+	///         ControlFlow::Break(())
+	///     } else {
+	///         ControlFlow::Continue(*x % 2 == 0)
+	///     }
+	/// });
+	///
+	/// assert_eq!(array, [2, 0, 4, 5, 6]);
+	/// ```
+	#[inline]
+	pub fn retain_while<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&mut T) -> core::ops::ControlFlow<(), bool>,
+	{
+		self.retain_with(|_, element| f(element));
+	}
+
+	/// removes the elements for which `filter` returns `true`, returning them
+	/// in an iterator. the kept elements are shifted down to fill the gaps.
+	///
+	/// if the iterator is dropped early, the rest of the array is still run
+	/// through `filter`, and the matching elements are dropped. if the iterator
+	/// is leaked, (ie; [`core::mem::forget()`]), the array may lose elements.
+	///
+	/// if `filter` panics, the elements not yet visited are kept.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// # use nyarray::array::Array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+	///
+	/// let evens: Array<8, i32> = array.extract_if(|x| *x % 2 == 0).collect();
+	/// assert_eq!(evens, [2, 4, 6]);
+	/// assert_eq!(array, [1, 3, 5]);
+	///
+	/// // stopping early still removes every match
+	/// let mut iter = array.extract_if(|x| *x > 1);
+	/// assert_eq!(iter.next(), Some(3));
+	/// drop(iter);
+	/// assert_eq!(array, [1]);
+	/// ```
+	pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, filter: F) -> ExtractIf<'_, N, T, F> {
+		let len = self.len();
+
+		unsafe {
+			// safety: the `ExtractIf` owns every element until it is dropped,
+			// so nothing can be observed twice if it is leaked
+			self.set_len(0);
+		}
+
+		ExtractIf {
+			array: self,
+			filter,
+			cur: 0,
+			removed: 0,
+			len,
+			panicked: false,
+		}
+	}
+
+	/// returns the elements in `range`, or `None` if the range is reversed or
+	/// goes past [`Self::len()`].
+	///
+	/// unlike indexing with a range, this never panics.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3, 4 => 8];
+	///
+	/// assert_eq!(array.get_range(1..3), Some(&[2, 3][..]));
+	/// assert_eq!(array.get_range(2..), Some(&[3, 4][..]));
+	/// assert_eq!(array.get_range(..=4), None);
+	/// assert_eq!(array.get_range(3..6), None);
+	/// assert_eq!(array.get_range(3..1), None);
+	/// ```
+	#[inline]
+	pub fn get_range<R: core::ops::RangeBounds<usize>>(&self, range: R) -> Option<&[T]> {
+		let range = resolve_range(range, self.len())?;
+		Some(&self.as_slice()[range])
+	}
+
+	/// returns the elements in `range` mutably, or `None` if the range is reversed
+	/// or goes past [`Self::len()`].
+	///
+	/// unlike indexing with a range, this never panics.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4 => 8];
+	///
+	/// if let Some(slice) = array.get_range_mut(1..3) {
+	///     slice.fill(0);
+	/// }
+	/// assert_eq!(array, [1, 0, 0, 4]);
+	///
+	/// assert_eq!(array.get_range_mut(2..5), None);
+	/// ```
+	#[inline]
+	pub fn get_range_mut<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Option<&mut [T]> {
+		let range = resolve_range(range, self.len())?;
+		Some(&mut self.as_mut_slice()[range])
+	}
+
+	/// push the `Ok` values of `iter` onto the end of the array, returning
+	/// the first `Err` encountered.
+	///
+	/// like [`Extend`], this stops once the array is full. the iterator is not
+	/// advanced past that point, so no values are lost if it's passed by reference.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let mut array = Array::<4, u8>::new();
+	/// let result = array.try_collect_into(["1", "2", "3"].map(str::parse));
+	/// assert!(result.is_ok());
+	/// assert_eq!(array, [1, 2, 3]);
+	///
+	/// let mut array = Array::<4, u8>::new();
+	/// let result = array.try_collect_into(["1", "nya", "3"].map(str::parse));
+	/// assert!(result.is_err());
+	/// assert_eq!(array, [1]);
+	///
+	/// // capacity exhausted
+	/// let mut array = Array::<2, u8>::new();
+	/// let mut iter = ["1", "2", "3"].into_iter().map(str::parse);
+	/// let result = array.try_collect_into(&mut iter);
+	/// assert!(result.is_ok());
+	/// assert_eq!(array, [1, 2]);
+	/// assert_eq!(iter.next(), Some(Ok(3)));
+	/// ```
+	pub fn try_collect_into<I, E>(&mut self, iter: I) -> Result<(), E>
+	where
+		I: IntoIterator<Item = Result<T, E>>,
+	{
+		let mut iter = iter.into_iter();
+
+		while self.len() < self.capacity() {
+			match iter.next() {
+				Some(element) => unsafe {
+					// safety: just confirmed there is enough space for another element
+					self.push_unchecked(element?);
+				},
+				None => break,
+			}
+		}
+
+		Ok(())
+	}
+
+	/// push elements from a type-erased iterator until the array is full or
+	/// the iterator runs out, returning how many elements were pushed.
+	///
+	/// this is for when the iterator type is hidden behind `dyn`, and can't be
+	/// passed to [`Extend`]. the iterator is not advanced past the last pushed
+	/// element, so no values are lost.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	/// let mut iter = 3..;
+	///
+	/// let iter: &mut dyn Iterator<Item = i32> = &mut iter;
+	/// assert_eq!(array.fill_from_dyn(iter), 2);
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	/// assert_eq!(iter.next(), Some(5));
+	/// ```
+	pub fn fill_from_dyn(&mut self, iter: &mut dyn Iterator<Item = T>) -> usize {
+		let start = self.len();
+
+		while self.len() < self.capacity() {
+			match iter.next() {
+				Some(element) => unsafe {
+					// safety: just confirmed there is enough space for another element
+					self.push_unchecked(element);
+				},
+				None => break,
+			}
+		}
+
+		self.len() - start
+	}
+
+	/// overwrite every element with clones of `value`.
+	///
+	/// only the initialized elements are touched, so the length doesn't change.
+	///
+	/// this forwards to [`slice::fill`], which is specialized for `Copy`
+	/// types; filling a byte array becomes a single `memset`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![0, 0, 0 => 4];
+	///
+	/// array.fill(7);
+	/// assert_eq!(array, [7, 7, 7]);
+	/// ```
+	#[inline]
+	pub fn fill(&mut self, value: T)
+	where
+		T: Clone,
+	{
+		self.as_mut_slice().fill(value);
+	}
+
+	/// overwrite every element with values returned by calling `f`.
+	///
+	/// only the initialized elements are touched, so the length doesn't change.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![0, 0, 0 => 4];
+	///
+	/// let mut n = 0;
+	/// array.fill_with(|| { n += 1; n });
+	/// assert_eq!(array, [1, 2, 3]);
+	/// ```
+	#[inline]
+	pub fn fill_with<F: FnMut() -> T>(&mut self, f: F) {
+		self.as_mut_slice().fill_with(f);
+	}
+
+	/// overwrite every element in `range` with clones of `value`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let mut array: Array<8, u8> = (1..=8).collect();
+	///
+	/// array.fill_range(2..6, 0);
+	/// assert_eq!(array, [1, 2, 0, 0, 0, 0, 7, 8]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `range` is reversed, or goes past [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.fill_range(1..4, 0); // panics
+	/// ```
+	#[inline]
+	pub fn fill_range<R: core::ops::RangeBounds<usize>>(&mut self, range: R, value: T)
+	where
+		T: Clone,
+	{
+		let Some(range) = resolve_range(range, self.len()) else {
+			panic!("range out of bounds");
+		};
+
+		self.as_mut_slice()[range].fill(value);
+	}
+
+	/// copies the elements in `src` to `dest`, overwriting what was there.
+	/// `src` and `dest` may overlap. this is [`slice::copy_within()`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+	///
+	/// array.move_range(0..3, 2);
+	/// assert_eq!(array, [1, 2, 1, 2, 3, 6]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `src` is reversed or goes past [`Self::len()`], or
+	/// if `dest + src.len()` goes past [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.move_range(0..2, 2); // panics
+	/// ```
+	#[inline]
+	pub fn move_range(&mut self, src: core::ops::Range<usize>, dest: usize)
+	where
+		T: Copy,
+	{
+		self.as_mut_slice().copy_within(src, dest);
+	}
+
+	/// swaps the elements in range `a` with the elements in range `b`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+	///
+	/// array.swap_ranges(0..2, 4..6);
+	/// assert_eq!(array, [5, 6, 3, 4, 1, 2]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if the ranges are reversed, go past [`Self::len()`],
+	/// don't have the same length, or overlap.
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+	/// array.swap_ranges(0..3, 2..5); // panics
+	/// ```
+	pub fn swap_ranges(&mut self, a: core::ops::Range<usize>, b: core::ops::Range<usize>) {
+		let len = self.len();
+
+		if a.start > a.end || a.end > len || b.start > b.end || b.end > len {
+			panic!("range out of bounds");
+		}
+
+		let count = a.end - a.start;
+
+		if count != b.end - b.start {
+			panic!("ranges have different lengths");
+		}
+
+		if a.start < b.end && b.start < a.end {
+			panic!("ranges overlap");
+		}
+
+		unsafe {
+			// safety: both ranges are in bounds, and don't overlap
+			let ptr = self.as_mut_ptr();
+			core::ptr::swap_nonoverlapping(ptr.add(a.start), ptr.add(b.start), count);
+		}
+	}
+
+	/// swaps the elements at `a` and `b`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	///
+	/// array.swap(0, 2);
+	/// assert_eq!(array, [3, 2, 1]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `a` or `b` are out of bounds.
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.swap(0, 3); // panics
+	/// ```
+	#[inline]
+	pub const fn swap(&mut self, a: usize, b: usize) {
+		self.as_mut_slice().swap(a, b);
+	}
+
+	/// reverses the order of the elements.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	///
+	/// array.reverse();
+	/// assert_eq!(array, [3, 2, 1]);
+	/// ```
+	#[inline]
+	pub const fn reverse(&mut self) {
+		self.as_mut_slice().reverse();
+	}
+
+	/// rotates the elements so that the element at `mid` becomes the first.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// array.rotate_left(2);
+	/// assert_eq!(array, [3, 4, 5, 1, 2]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `mid` is greater than [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.rotate_left(4); // panics
+	/// ```
+	#[inline]
+	pub fn rotate_left(&mut self, mid: usize) {
+		self.as_mut_slice().rotate_left(mid);
+	}
+
+	/// rotates the elements so that the last `k` elements become the first.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3, 4, 5 => 8];
+	///
+	/// array.rotate_right(2);
+	/// assert_eq!(array, [4, 5, 1, 2, 3]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `k` is greater than [`Self::len()`].
+	///
+	/// ```should_panic
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2, 3 => 8];
+	/// array.rotate_right(4); // panics
+	/// ```
+	#[inline]
+	pub fn rotate_right(&mut self, k: usize) {
+		self.as_mut_slice().rotate_right(k);
+	}
+
+	/// sorts the array, keeping equal elements in their original order.
+	///
+	/// this doesn't allocate; it's an in-place merge sort, taking
+	/// `O(n * log(n)^2)` time and `O(log(n))` stack space. that's slower than
+	/// the standard library's stable sort for large inputs, but available in
+	/// `no_std`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd') => 8];
+	///
+	/// array.sort_stable();
+	/// assert_eq!(array, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+	/// ```
+	#[inline]
+	pub fn sort_stable(&mut self)
+	where
+		T: Ord,
+	{
+		merge_sort(self.as_mut_slice(), &mut |a: &T, b: &T| a < b);
+	}
+
+	/// sorts the array, keeping equal elements in their original order.
+	/// this is [`slice::sort()`].
+	///
+	/// this method is not available in `no_std`, as the standard library's
+	/// stable sort allocates. see [`Self::sort_stable()`] or
+	/// [`Self::sort_unstable()`] instead.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![3, 1, 2 => 4];
+	///
+	/// array.sort();
+	/// assert_eq!(array, [1, 2, 3]);
+	/// ```
+	#[cfg(feature = "std")]
+	#[inline]
+	pub fn sort(&mut self)
+	where
+		T: Ord,
+	{
+		self.as_mut_slice().sort();
+	}
+
+	/// sorts the array with a comparison function, keeping equal elements in
+	/// their original order. this is [`slice::sort_by()`].
+	///
+	/// this method is not available in `no_std`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![3, 1, 2 => 4];
+	///
+	/// array.sort_by(|a, b| b.cmp(a));
+	/// assert_eq!(array, [3, 2, 1]);
+	/// ```
+	#[cfg(feature = "std")]
+	#[inline]
+	pub fn sort_by<F: FnMut(&T, &T) -> core::cmp::Ordering>(&mut self, compare: F) {
+		self.as_mut_slice().sort_by(compare);
+	}
+
+	/// sorts the array with a key extraction function, keeping equal elements
+	/// in their original order. this is [`slice::sort_by_key()`].
+	///
+	/// this method is not available in `no_std`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![-3i32, 1, -2 => 4];
+	///
+	/// array.sort_by_key(|x| x.abs());
+	/// assert_eq!(array, [1, -2, -3]);
+	/// ```
+	#[cfg(feature = "std")]
+	#[inline]
+	pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, f: F) {
+		self.as_mut_slice().sort_by_key(f);
+	}
+
+	/// sorts the array, without keeping equal elements in order.
+	/// this is [`slice::sort_unstable()`], and doesn't allocate.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![5, 3, 1, 4, 2 => 8];
+	///
+	/// array.sort_unstable();
+	/// assert_eq!(array, [1, 2, 3, 4, 5]);
+	/// ```
+	#[inline]
+	pub fn sort_unstable(&mut self)
+	where
+		T: Ord,
+	{
+		self.as_mut_slice().sort_unstable();
+	}
+
+	/// binary searches a sorted array for `x`. this is
+	/// [`slice::binary_search()`].
+	///
+	/// returns `Ok` with the index of a matching element, or `Err` with the
+	/// index `x` could be inserted at to keep the array sorted.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 3, 5, 7 => 8];
+	///
+	/// assert_eq!(array.binary_search(&5), Ok(2));
+	/// assert_eq!(array.binary_search(&4), Err(2));
+	/// assert_eq!(array.binary_search(&8), Err(4));
+	/// ```
+	#[inline]
+	pub fn binary_search(&self, x: &T) -> Result<usize, usize>
+	where
+		T: Ord,
+	{
+		self.as_slice().binary_search(x)
+	}
+
+	/// binary searches a sorted array with a comparison function. this is
+	/// [`slice::binary_search_by()`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 3, 5, 7 => 8];
+	///
+	/// assert_eq!(array.binary_search_by(|x| x.cmp(&7)), Ok(3));
+	/// assert_eq!(array.binary_search_by(|x| x.cmp(&0)), Err(0));
+	/// ```
+	#[inline]
+	pub fn binary_search_by<F: FnMut(&T) -> core::cmp::Ordering>(
+		&self,
+		f: F,
+	) -> Result<usize, usize> {
+		self.as_slice().binary_search_by(f)
+	}
+
+	/// binary searches an array sorted by a key extraction function. this is
+	/// [`slice::binary_search_by_key()`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![(0, 'a'), (2, 'b'), (4, 'c') => 4];
+	///
+	/// assert_eq!(array.binary_search_by_key(&2, |&(k, _)| k), Ok(1));
+	/// assert_eq!(array.binary_search_by_key(&3, |&(k, _)| k), Err(2));
+	/// ```
+	#[inline]
+	pub fn binary_search_by_key<K: Ord, F: FnMut(&T) -> K>(
+		&self,
+		key: &K,
+		f: F,
+	) -> Result<usize, usize> {
+		self.as_slice().binary_search_by_key(key, f)
+	}
+
+	/// binary search the array for `key`. the array is assumed to be sorted.
+	///
+	/// on a match, returns `Ok((index, element))` with the matched element. otherwise,
+	/// returns `Err(index)` with where `key` could be inserted to keep the array sorted.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array: Array<8, String> = ["a", "c", "e"].into_iter().map(String::from).collect();
+	///
+	/// assert_eq!(array.search("c"), Ok((1, &String::from("c"))));
+	/// assert_eq!(array.search("d"), Err(2));
+	/// ```
+	pub fn search<Q: Ord + ?Sized>(&self, key: &Q) -> Result<(usize, &T), usize>
+	where
+		T: core::borrow::Borrow<Q>,
+	{
+		let slice = self.as_slice();
+		slice
+			.binary_search_by(|element| element.borrow().cmp(key))
+			.map(|index| (index, &slice[index]))
+	}
+
+	/// returns the index where the first occurrence of `needle` starts, or
+	/// `None` if it doesn't appear. an empty `needle` is found at `0`.
+	///
+	/// this is a simple scan, taking `O(n * m)` time.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array: Array<16, u8> = b"GET / HTTP\r\n\r\n".iter().copied().collect();
+	///
+	/// assert_eq!(array.find_subslice(b"GET"), Some(0));
+	/// assert_eq!(array.find_subslice(b"\r\n"), Some(10));
+	/// assert_eq!(array.find_subslice(b"POST"), None);
+	/// ```
+	pub fn find_subslice(&self, needle: &[T]) -> Option<usize>
+	where
+		T: PartialEq,
+	{
+		if needle.is_empty() {
+			return Some(0);
+		}
+
+		self.as_slice().windows(needle.len()).position(|window| window == needle)
+	}
+
+	/// returns an iterator over the subslices separated by `delim`, which may
+	/// be more than one element long.
+	///
+	/// this behaves like [`str::split()`]: leading, trailing, or consecutive
+	/// delimiters produce empty subslices, and an empty array produces one
+	/// empty subslice.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let array: Array<16, u8> = b"a\r\nbc\r\n\r\n".iter().copied().collect();
+	///
+	/// let mut lines = array.split_on(b"\r\n");
+	/// assert_eq!(lines.next(), Some(&b"a"[..]));
+	/// assert_eq!(lines.next(), Some(&b"bc"[..]));
+	/// assert_eq!(lines.next(), Some(&b""[..]));
+	/// assert_eq!(lines.next(), Some(&b""[..]));
+	/// assert_eq!(lines.next(), None);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `delim` is empty.
+	pub fn split_on<'a>(&'a self, delim: &'a [T]) -> impl Iterator<Item = &'a [T]>
+	where
+		T: PartialEq,
+	{
+		if delim.is_empty() {
+			panic!("delimiter must be non-empty");
+		}
+
+		let mut rest = Some(self.as_slice());
+
+		core::iter::from_fn(move || {
+			let slice = rest?;
+
+			match slice.windows(delim.len()).position(|window| window == delim) {
+				Some(index) => {
+					rest = Some(&slice[index + delim.len()..]);
+					Some(&slice[..index])
+				}
+				None => {
+					rest = None;
+					Some(slice)
+				}
+			}
+		})
+	}
+
+	/// returns an iterator over the elements and their indices.
+	/// this is the same as `array.iter().enumerate()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array!['a', 'b', 'c' => 3];
+	///
+	/// for (i, c) in array.enumerate() {
+	///     assert_eq!(array[i], *c);
+	/// }
+	///
+	/// assert!(array.enumerate().eq([(0, &'a'), (1, &'b'), (2, &'c')]));
+	/// ```
+	#[inline]
+	pub fn enumerate(&self) -> impl Iterator<Item = (usize, &T)> {
+		self.as_slice().iter().enumerate()
+	}
+
+	/// returns an iterator over copies of the elements.
+	/// this is the same as `array.iter().copied()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 4];
+	///
+	/// assert_eq!(array.copied().sum::<i32>(), 6);
+	/// ```
+	#[inline]
+	pub fn copied(&self) -> impl Iterator<Item = T> + '_
+	where
+		T: Copy,
+	{
+		self.as_slice().iter().copied()
+	}
+
+	/// returns an iterator over clones of the elements.
+	/// this is the same as `array.iter().cloned()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 4];
+	///
+	/// assert_eq!(array.cloned().max(), Some(3));
+	/// ```
+	#[inline]
+	pub fn cloned(&self) -> impl Iterator<Item = T> + '_
+	where
+		T: Clone,
+	{
+		self.as_slice().iter().cloned()
+	}
+
+	/// call `f` on every element in order, stopping at the first error.
+	/// this is the same as `array.iter().try_for_each(f)`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3, 4 => 4];
+	///
+	/// let positive = array.try_for_each(|x| if *x > 0 { Ok(()) } else { Err(*x) });
+	/// assert_eq!(positive, Ok(()));
+	///
+	/// let array = array![1, -2, 3, -4 => 4];
+	///
+	/// let positive = array.try_for_each(|x| if *x > 0 { Ok(()) } else { Err(*x) });
+	/// assert_eq!(positive, Err(-2));
+	/// ```
+	#[inline]
+	pub fn try_for_each<E, F: FnMut(&T) -> Result<(), E>>(&self, f: F) -> Result<(), E> {
+		self.as_slice().iter().try_for_each(f)
+	}
+
+	/// returns an iterator over runs of consecutive elements, where `pred`
+	/// returns `true` for every adjacent pair in a run.
+	/// this is the same as `array.as_slice().chunk_by(pred)`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 1, 2, 3, 3 => 8];
+	///
+	/// let mut groups = array.chunk_by(|a, b| a == b);
+	/// assert_eq!(groups.next(), Some(&[1, 1][..]));
+	/// assert_eq!(groups.next(), Some(&[2][..]));
+	/// assert_eq!(groups.next(), Some(&[3, 3][..]));
+	/// assert_eq!(groups.next(), None);
+	/// ```
+	#[inline]
+	pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, pred: F) -> impl Iterator<Item = &[T]> {
+		self.as_slice().chunk_by(pred)
+	}
+
+	/// clone the array, using `f` to clone each element. short-circuits on
+	/// the first `Err`, dropping the elements cloned so far.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1, 2, 3 => 4];
+	///
+	/// let clone = array.try_clone_with(|x| Ok::<_, ()>(*x));
+	/// assert_eq!(clone, Ok(array![1, 2, 3]));
+	///
+	/// let clone = array.try_clone_with(|x| if *x < 3 { Ok(*x) } else { Err(*x) });
+	/// assert_eq!(clone, Err(3));
+	/// ```
+	pub fn try_clone_with<F: FnMut(&T) -> Result<T, E>, E>(&self, mut f: F) -> Result<Self, E> {
+		let mut out = Self::new();
+
+		for element in self {
+			unsafe {
+				// safety: `out` has the same capacity as `self`
+				out.push_unchecked(f(element)?);
+			}
+		}
+
+		Ok(out)
+	}
+
+	/// initialize a prefix of the spare capacity with `f`, then grow the
+	/// array to include it.
+	///
+	/// `f` is given the uninitialized spare capacity, and returns how many
+	/// elements at the start of it were initialized. the length is then
+	/// advanced by that count.
+	///
+	/// ## safety
+	///
+	/// the count returned by `f` must be honest: every element `0..count` of the
+	/// spare slice must have been initialized. over-reporting exposes uninitialized
+	/// memory, and is the caller's bug.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 8];
+	///
+	/// unsafe {
+	///     array.fill_spare(|spare| {
+	///         for (i, slot) in spare.iter_mut().take(3).enumerate() {
+	///             slot.write(i as i32 + 3);
+	///         }
+	///         // safety: initialized the first 3 elements
+	///         3
+	///     });
+	/// }
+	///
+	/// assert_eq!(array, [1, 2, 3, 4, 5]);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if the count returned by `f` is greater than the
+	/// spare capacity.
+	#[expect(clippy::missing_safety_doc, reason = "there is a safety doc, not sure why the lint still generates")]
+	pub unsafe fn fill_spare<F: FnOnce(&mut [core::mem::MaybeUninit<T>]) -> usize>(&mut self, f: F) {
+		let len = self.len();
+		let spare = &mut self.buf[len..];
+		let spare_len = spare.len();
+
+		let count = f(spare);
+
+		assert!(count <= spare_len, "fill exceeds capacity");
+
+		unsafe {
+			// safety: caller ensures `count` elements were initialized
+			self.set_len(len + count);
+		}
+	}
+
+	/// copy as many elements from the start of `src` as fit onto the end of
+	/// the array, returning how many were copied.
+	///
+	/// unlike [`Self::extend_from_slice()`], this never panics; elements that
+	/// don't fit are ignored.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut array = array![1, 2 => 4];
+	///
+	/// assert_eq!(array.fill_spare_from_slice(&[3]), 1);
+	/// assert_eq!(array, [1, 2, 3]);
+	///
+	/// assert_eq!(array.fill_spare_from_slice(&[4, 5, 6]), 1);
+	/// assert_eq!(array, [1, 2, 3, 4]);
+	/// ```
+	#[inline]
+	pub const fn fill_spare_from_slice(&mut self, src: &[T]) -> usize
+	where
+		T: Copy,
+	{
+		let len = self.len();
+		let spare = self.capacity() - len;
+		let count = if src.len() < spare { src.len() } else { spare };
+
+		unsafe {
+			// safety: `count` elements fit in the spare capacity, and `src`
+			// can't overlap it, as nothing can borrow uninitialized elements
+			core::ptr::copy_nonoverlapping(src.as_ptr(), self.as_mut_ptr().add(len), count);
+			self.set_len(len + count);
+		}
+
+		count
+	}
+
+	/// shared compaction loop for the filtering methods.
+	///
+	/// `f` is given the elements kept so far and the current element, and
+	/// returns `Continue(true)` to keep it, `Continue(false)` to drop it,
+	/// or `Break(())` to stop and keep every remaining element as-is.
+	///
+	/// if `f` or a destructor panics, the unprocessed elements are kept,
+	/// and the array is left in a valid state.
+	fn retain_with<F>(&mut self, mut f: F)
+	where
+		F: FnMut(&mut [T], &mut T) -> core::ops::ControlFlow<(), bool>,
+	{
+		struct Guard<'a, const N: usize, T> {
+			array: &'a mut Array<N, T>,
+			processed: usize,
+			deleted: usize,
+			len: usize,
+		}
+
+		impl<const N: usize, T> Drop for Guard<'_, N, T> {
+			fn drop(&mut self) {
+				unsafe {
+					if self.deleted > 0 {
+						let ptr = self.array.as_mut_ptr();
+
+						// shift whatever wasn't processed down over the gap
+						core::ptr::copy(
+							ptr.add(self.processed),
+							ptr.add(self.processed - self.deleted),
+							self.len - self.processed,
+						);
+					}
+
+					self.array.set_len(self.len - self.deleted);
+				}
+			}
+		}
+
+		let len = self.len();
+
+		unsafe {
+			// safety: if anything panics, the guard restores the length
+			self.set_len(0);
+		}
+
+		let mut guard = Guard {
+			array: self,
+			processed: 0,
+			deleted: 0,
+			len,
+		};
+
+		while guard.processed < guard.len {
+			let ptr = guard.array.as_mut_ptr();
+			let kept_len = guard.processed - guard.deleted;
+
+			let (kept, element) = unsafe {
+				// safety: `0..kept_len` and `processed` are initialized and disjoint
+				(
+					core::slice::from_raw_parts_mut(ptr, kept_len),
+					&mut *ptr.add(guard.processed),
+				)
+			};
+
+			match f(kept, element) {
+				core::ops::ControlFlow::Break(()) => break,
+				core::ops::ControlFlow::Continue(true) => {
+					if guard.deleted > 0 {
+						unsafe {
+							core::ptr::copy_nonoverlapping(element, ptr.add(kept_len), 1);
+						}
+					}
+					guard.processed += 1;
+				}
+				core::ops::ControlFlow::Continue(false) => {
+					// bump counters first, so a panicking destructor isn't run twice
+					guard.processed += 1;
+					guard.deleted += 1;
+					unsafe {
+						core::ptr::drop_in_place(element);
+					}
+				}
+			}
+		}
+	}
+}
+
+impl<const N: usize> Array<N, u8> {
+	/// returns an iterator over `C`-byte blocks of the array. if the length
+	/// isn't a multiple of `C`, the last block is filled out with `pad`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let array = array![1u8, 2, 3, 4, 5 => 8];
+	///
+	/// let mut blocks = array.chunks_padded::<4>(0);
+	/// assert_eq!(blocks.next(), Some([1, 2, 3, 4]));
+	/// assert_eq!(blocks.next(), Some([5, 0, 0, 0]));
+	/// assert_eq!(blocks.next(), None);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `C` is `0`.
+	#[inline]
+	pub fn chunks_padded<const C: usize>(&self, pad: u8) -> impl Iterator<Item = [u8; C]> {
+		self.as_slice().chunks(C).map(move |chunk| {
+			let mut block = [pad; C];
+			block[..chunk.len()].copy_from_slice(chunk);
+			block
+		})
+	}
+}
+
+impl<const N: usize> Array<N, char> {
+	/// push every `char` of `s` onto the end of the array. returns `Err(())`
+	/// if they don't all fit, in which case the array is left untouched.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array::Array;
+	/// let mut array = Array::<8, char>::new();
+	///
+	/// array.push_str("nya").unwrap();
+	/// array.push_str("~").unwrap();
+	/// assert_eq!(array, ['n', 'y', 'a', '~']);
+	///
+	/// assert!(array.push_str("meow!").is_err());
+	/// assert_eq!(array, ['n', 'y', 'a', '~']);
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn push_str(&mut self, s: &str) -> Result<(), ()> {
+		if s.chars().count() > self.capacity() - self.len() {
+			return Err(());
+		}
+
+		for c in s.chars() {
+			unsafe {
+				// safety: just confirmed every char fits
+				self.push_unchecked(c);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+macro_rules! impl_numeric {
+	($($t:ty)*) => {$(
+		impl<const N: usize> Array<N, $t> {
+			/// returns the dot product of two arrays.
+			///
+			/// for integer types, this is computed in the element type, so it
+			/// overflows like regular arithmetic; see the panics section.
+			///
+			/// ## examples
+			///
+			/// ```
+			/// # use nyarray::array;
+			/// let a = array![1i32, 2, 3 => 4];
+			/// let b = array![4, -5, 6 => 4];
+			///
+			/// assert_eq!(a.dot(&b), 12);
+			/// ```
+			///
+			/// ## panics
+			///
+			/// this method panics if the arrays don't have the same length.
+			///
+			/// for integer types, it also panics in debug builds if a product or
+			/// the sum overflows. in release builds, it wraps instead.
+			///
+			/// ```should_panic
+			/// # use nyarray::array;
+			/// let a = array![1i32, 2, 3 => 4];
+			/// a.dot(&array![1, 2 => 4]); // panics
+			/// ```
+			pub fn dot(&self, other: &Self) -> $t {
+				if self.len() != other.len() {
+					panic!("length mismatch");
+				}
+
+				self.as_slice().iter()
+					.zip(other.as_slice())
+					.fold(<$t>::default(), |acc, (a, b)| acc + a * b)
+			}
+
+			/// returns the squared euclidean norm, which is the dot product of the
+			/// array with itself.
+			///
+			/// ## examples
+			///
+			/// ```
+			/// # use nyarray::array;
+			/// let array = array![3.0f64, 4.0 => 4];
+			///
+			/// assert_eq!(array.norm_squared(), 25.0);
+			/// ```
+			///
+			/// ## panics
+			///
+			/// for integer types, this method panics in debug builds if the result
+			/// overflows, like [`Self::dot()`]. in release builds, it wraps instead.
+			pub fn norm_squared(&self) -> $t {
+				self.dot(self)
+			}
+		}
+	)*};
+}
+
+impl_numeric!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+macro_rules! impl_moving_average {
+	(float: $($t:ty)*) => {$(
+		impl<const N: usize> Array<N, $t> {
+			/// returns the averages of every window of `W` consecutive elements.
+			///
+			/// no padding is done at the edges, so the output has
+			/// `self.len() - W + 1` elements, where element `i` is the average of
+			/// `self[i..i + W]`. if there are less than `W` elements, the output is empty.
+			///
+			/// ## examples
+			///
+			/// ```
+			/// # use nyarray::array;
+			/// let array = array![1.0f64, 2.0, 3.0, 4.0 => 4];
+			///
+			/// assert_eq!(array.moving_average::<2>(), [1.5, 2.5, 3.5]);
+			/// ```
+			///
+			/// ## panics
+			///
+			/// this method panics if `W` is `0`.
+			pub fn moving_average<const W: usize>(&self) -> Array<N, $t> {
+				let mut out = Array::new();
+
+				for window in self.as_slice().windows(W) {
+					let sum = window.iter().fold(<$t>::default(), |acc, x| acc + x);
+					unsafe {
+						// safety: there are never more windows than elements
+						out.push_unchecked(sum / W as $t);
+					}
+				}
+
+				out
+			}
+		}
+	)*};
+	($kind:ident: $($t:ty)*) => {$(
+		impl<const N: usize> Array<N, $t> {
+			/// returns the averages of every window of `W` consecutive elements.
+			///
+			/// no padding is done at the edges, so the output has
+			/// `self.len() - W + 1` elements, where element `i` is the average of
+			/// `self[i..i + W]`. if there are less than `W` elements, the output is empty.
+			///
+			/// the average is rounded like integer division. it is computed
+			/// without ever summing the window, so it can't overflow.
+			///
+			/// ## examples
+			///
+			/// ```
+			/// # use nyarray::array;
+			/// let array = array![200u8, 200, 100, 40 => 4];
+			///
+			/// assert_eq!(array.moving_average::<2>(), [200, 150, 70]);
+			/// ```
+			///
+			/// ## panics
+			///
+			/// this method panics if `W` is `0`, or if `W` is greater than the
+			/// element type's maximum value.
+			///
+			/// ```should_panic
+			/// # use nyarray::array;
+			/// let array = array![1u8, 2, 3 => 4];
+			/// array.moving_average::<256>(); // panics
+			/// ```
+			pub fn moving_average<const W: usize>(&self) -> Array<N, $t> {
+				let w = <$t>::try_from(W).unwrap_or_else(|_| panic!("window exceeds type range"));
+
+				let mut out = Array::new();
+
+				for window in self.as_slice().windows(W) {
+					// the sum is tracked as `quot * w + rem`, with `rem` kept within
+					// `-w..w`, so neither ever leaves the range of the window's average
+					let mut quot: $t = 0;
+					let mut rem: $t = 0;
+
+					for x in window {
+						let carry = impl_moving_average!(@carry $kind, rem, x % w, w);
+						quot += x / w + carry;
+					}
+
+					impl_moving_average!(@truncate $kind, quot, rem);
+
+					unsafe {
+						// safety: there are never more windows than elements
+						out.push_unchecked(quot);
+					}
+				}
+
+				out
+			}
+		}
+	)*};
+	// adds `x` to `rem`, returning how much to carry into the quotient
+	(@carry unsigned, $rem:ident, $x:expr, $w:ident) => {{
+		let x = $x;
+		if $rem >= $w - x {
+			$rem -= $w - x;
+			1
+		} else {
+			$rem += x;
+			0
+		}
+	}};
+	(@carry signed, $rem:ident, $x:expr, $w:ident) => {{
+		let x = $x;
+		if x >= 0 && $rem >= $w - x {
+			$rem -= $w - x;
+			1
+		} else if x < 0 && $rem <= -($w + x) {
+			$rem += $w + x;
+			-1
+		} else {
+			$rem += x;
+			0
+		}
+	}};
+	// the quotient is already floored, which is truncation for unsigned
+	(@truncate unsigned, $quot:ident, $rem:ident) => {};
+	// rounds toward zero when the quotient and remainder disagree in sign
+	(@truncate signed, $quot:ident, $rem:ident) => {
+		if $quot > 0 && $rem < 0 {
+			$quot -= 1;
+		} else if $quot < 0 && $rem > 0 {
+			$quot += 1;
+		}
+	};
+}
+
+impl_moving_average!(unsigned: u8 u16 u32 u64 u128 usize);
+impl_moving_average!(signed: i8 i16 i32 i64 i128 isize);
+impl_moving_average!(float: f32 f64);
+
+macro_rules! impl_elementwise {
+	($($op:ident $op_fn:ident $assign:ident $assign_fn:ident)*) => {$(
+		/// applies the operator elementwise.
+		///
+		/// ## panics
+		///
+		/// panics if the arrays don't have the same length.
+		impl<const N: usize, T: core::ops::$op<Output = T> + Copy> core::ops::$op for Array<N, T> {
+			type Output = Self;
+
+			fn $op_fn(mut self, rhs: Self) -> Self::Output {
+				core::ops::$assign::$assign_fn(&mut self, rhs);
+				self
+			}
+		}
+
+		/// applies the operator elementwise.
+		///
+		/// ## panics
+		///
+		/// panics if the arrays don't have the same length.
+		impl<const N: usize, T: core::ops::$op<Output = T> + Copy> core::ops::$assign for Array<N, T> {
+			fn $assign_fn(&mut self, rhs: Self) {
+				if self.len() != rhs.len() {
+					panic!("length mismatch");
+				}
+
+				for (a, b) in self.as_mut_slice().iter_mut().zip(rhs.as_slice()) {
+					*a = core::ops::$op::$op_fn(*a, *b);
+				}
+			}
+		}
+	)*};
+}
+
+impl_elementwise! {
+	Add add AddAssign add_assign
+	Sub sub SubAssign sub_assign
+	Mul mul MulAssign mul_assign
+}
+
+impl<const N: usize, T> Drop for Array<N, T> {
+	fn drop(&mut self) {
+		self.clear();
+	}
+}
+
+impl<const N: usize, T> Default for Array<N, T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const N: usize, T: Clone> Clone for Array<N, T> {
+	fn clone(&self) -> Self {
+		let mut out = Self::new();
+
+		for element in self.as_slice() {
+			unsafe {
+				// safety: `out` has the same capacity as `self`
+				out.push_unchecked(element.clone());
+			}
+		}
+
+		out
+	}
+
+	/// clones `source` into `self`, reusing the elements already in `self`
+	/// through [`Clone::clone_from()`] rather than dropping them.
+	fn clone_from(&mut self, source: &Self) {
+		self.truncate(source.len());
+
+		let (init, tail) = source.as_slice().split_at(self.len());
+		self.as_mut_slice().clone_from_slice(init);
+
+		for element in tail {
+			unsafe {
+				// safety: `self` has the same capacity as `source`
+				self.push_unchecked(element.clone());
+			}
+		}
+	}
+}
+
+/// clones the elements of a slice into an array. the error is the length
+/// of the slice, if it has more elements than the array capacity.
+impl<const N: usize, T: Clone> TryFrom<&[T]> for Array<N, T> {
+	type Error = usize;
+
+	fn try_from(slice: &[T]) -> Result<Self, Self::Error> {
+		Self::try_from_slice(slice).map_err(|()| slice.len())
+	}
+}
+
+/// moves the elements of an array into a full [`Array`] of the same capacity.
+///
+/// ## examples
+///
+/// ```
+/// # use nyarray::array::Array;
+/// let array: Array<4, i32> = [1, 2, 3, 4].into();
+/// assert_eq!(array, [1, 2, 3, 4]);
+/// assert_eq!(array.len(), array.capacity());
+/// ```
+impl<const N: usize, T> From<[T; N]> for Array<N, T> {
+	fn from(array: [T; N]) -> Self {
+		Self::from_parts(array)
+	}
+}
+
+impl<const N: usize, T> AsRef<[T]> for Array<N, T> {
+	fn as_ref(&self) -> &[T] {
+		self.as_slice()
+	}
+}
+
+impl<const N: usize, T> AsMut<[T]> for Array<N, T> {
+	fn as_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
 
 impl<const N: usize, T> core::borrow::Borrow<[T]> for Array<N, T> {
 	fn borrow(&self) -> &[T] {
 		self.as_slice()
 	}
-}
+}
+
+impl<const N: usize, T> core::borrow::BorrowMut<[T]> for Array<N, T> {
+	fn borrow_mut(&mut self) -> &mut [T] {
+		self.as_mut_slice()
+	}
+}
+
+impl<const N: usize, T> core::ops::Deref for Array<N, T> {
+	type Target = [T];
+	fn deref(&self) -> &Self::Target {
+		self.as_slice()
+	}
+}
+
+impl<const N: usize, T> core::ops::DerefMut for Array<N, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.as_mut_slice()
+	}
+}
+
+impl<const N: usize, T, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for Array<N, T> {
+	type Output = I::Output;
+	fn index(&self, index: I) -> &Self::Output {
+		core::ops::Index::index(self.as_slice(), index)
+	}
+}
+
+impl<const N: usize, T, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for Array<N, T> {
+	fn index_mut(&mut self, index: I) -> &mut Self::Output {
+		core::ops::IndexMut::index_mut(self.as_mut_slice(), index)
+	}
+}
+
+impl<const N: usize, T> Extend<T> for Array<N, T> {
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		let mut iter = iter.into_iter();
+
+		// the iterator's lower bound, clamped to the remaining capacity,
+		// can be pushed without checking capacity each time
+		let bulk = iter.size_hint().0.min(self.capacity() - self.len());
+
+		for _ in 0..bulk {
+			match iter.next() {
+				Some(i) => unsafe {
+					// safety: `bulk` never exceeds the remaining capacity
+					self.push_unchecked(i);
+				},
+				None => return,
+			}
+		}
+
+		for i in iter {
+			if self.push_checked(i).is_err() {
+				break;
+			}
+		}
+	}
+}
+
+impl<'a, const N: usize, T: Copy> Extend<&'a T> for Array<N, T> {
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+
+/// retain the pairs of elements `(a[i], b[i])` for which `f` returns `true`,
+/// removing the rest from both arrays. this keeps two arrays used as columns
+/// of the same data aligned.
+///
+/// `f` is called on every pair before anything is removed, so if it panics,
+/// both arrays are left unchanged.
+///
+/// ## examples
+///
+/// ```
+/// # use nyarray::array;
+/// let mut names = array!["a", "b", "c", "d" => 4];
+/// let mut scores = array![10, 40, 20, 50 => 4];
+///
+/// array::retain_parallel(&mut names, &mut scores, |_, score| *score >= 30);
+///
+/// assert_eq!(names, ["b", "d"]);
+/// assert_eq!(scores, [40, 50]);
+/// ```
+///
+/// ## panics
+///
+/// this function panics if the arrays don't have the same length.
+///
+/// ```should_panic
+/// # use nyarray::array;
+/// let mut a = array![1, 2, 3 => 4];
+/// let mut b = array![1, 2 => 4];
+/// array::retain_parallel(&mut a, &mut b, |_, _| true); // panics
+/// ```
+pub fn retain_parallel<const N: usize, A, B, F: FnMut(&A, &B) -> bool>(
+	a: &mut Array<N, A>,
+	b: &mut Array<N, B>,
+	mut f: F,
+) {
+	if a.len() != b.len() {
+		panic!("length mismatch");
+	}
+
+	let keep: Array<N, bool> = a.iter().zip(b.iter()).map(|(a, b)| f(a, b)).collect();
+
+	let mut i = 0;
+	a.retain(|_| {
+		i += 1;
+		keep[i - 1]
+	});
+
+	let mut i = 0;
+	b.retain(|_| {
+		i += 1;
+		keep[i - 1]
+	});
+}
+
+/// stable sort that doesn't allocate. runs are insertion sorted, then merged
+/// in place with [`merge_in_place()`].
+fn merge_sort<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F) {
+	const RUN: usize = 16;
+
+	let len = v.len();
+
+	if len <= RUN {
+		for i in 1..len {
+			let mut j = i;
+			while j > 0 && is_less(&v[j], &v[j - 1]) {
+				v.swap(j, j - 1);
+				j -= 1;
+			}
+		}
+		return;
+	}
+
+	let mid = len / 2;
+	merge_sort(&mut v[..mid], is_less);
+	merge_sort(&mut v[mid..], is_less);
+
+	// already in order
+	if !is_less(&v[mid], &v[mid - 1]) {
+		return;
+	}
+
+	merge_in_place(v, mid, is_less);
+}
+
+/// stably merge the sorted runs `v[..mid]` and `v[mid..]` without a buffer,
+/// by rotating the middle of the two runs into place, then recursing on
+/// both halves.
+fn merge_in_place<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], mid: usize, is_less: &mut F) {
+	let len = v.len();
+
+	if mid == 0 || mid == len {
+		return;
+	}
+
+	if len == 2 {
+		if is_less(&v[1], &v[0]) {
+			v.swap(0, 1);
+		}
+		return;
+	}
+
+	let (cut_l, cut_r) = if mid > len - mid {
+		// split the left run in half, and find where its middle goes in the right.
+		// elements equal to the pivot stay on the right, after it.
+		let cut_l = mid / 2;
+		let pivot = &v[cut_l];
+		(cut_l, mid + v[mid..].partition_point(|x| is_less(x, pivot)))
+	} else {
+		// split the right run in half, and find where its middle goes in the left.
+		// elements equal to the pivot stay on the left, before it.
+		let cut_r = mid + (len - mid) / 2;
+		let pivot = &v[cut_r];
+		(v[..mid].partition_point(|x| !is_less(pivot, x)), cut_r)
+	};
+
+	v[cut_l..cut_r].rotate_left(mid - cut_l);
+
+	let new_mid = cut_l + (cut_r - mid);
+	let (left, right) = v.split_at_mut(new_mid);
+	merge_in_place(left, cut_l, is_less);
+	merge_in_place(right, cut_r - new_mid, is_less);
+}
+
+/// resolve `range` into `start..end`, or `None` if it is reversed or not
+/// in `0..=len`.
+fn resolve_range<R: core::ops::RangeBounds<usize>>(range: R, len: usize) -> Option<core::ops::Range<usize>> {
+	let start = match range.start_bound() {
+		core::ops::Bound::Included(&start) => start,
+		core::ops::Bound::Excluded(&start) => start.checked_add(1)?,
+		core::ops::Bound::Unbounded => 0,
+	};
+
+	let end = match range.end_bound() {
+		core::ops::Bound::Included(&end) => end.checked_add(1)?,
+		core::ops::Bound::Excluded(&end) => end,
+		core::ops::Bound::Unbounded => len,
+	};
+
+	if start > end || end > len {
+		return None;
+	}
+
+	Some(start..end)
+}
+
+#[doc(hidden)]
+pub fn from_elem<const N: usize, T: Clone>(elem: T, n: usize) -> Array<N, T> {
+	let mut array = Array::new();
+	for _ in 0..n {
+		array.push(elem.clone());
+	}
+	array
+}
+
+
+/// iterator for [`Array`].
+pub struct IntoIter<const N: usize, T> {
+	inner: [core::mem::MaybeUninit<T>; N],
+	cur: usize,
+	end: usize,
+}
+
+impl<const N: usize, T> IntoIter<N, T> {
+	/// returns the elements that haven't been yielded yet as a slice.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut iter = array![1, 2, 3, 4 => 4].into_iter();
+	///
+	/// iter.next();
+	/// iter.next_back();
+	/// assert_eq!(iter.as_slice(), [2, 3]);
+	/// ```
+	#[inline]
+	pub const fn as_slice(&self) -> &[T] {
+		unsafe {
+			// safety: elements `cur..end` are always initialized. they're only
+			// read out by moving `cur` or `end` past them.
+			let ptr = (&self.inner as *const core::mem::MaybeUninit<T> as *const T).add(self.cur);
+			core::slice::from_raw_parts(ptr, self.end - self.cur)
+		}
+	}
+
+	/// returns the elements that haven't been yielded yet as a mutable slice.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::array;
+	/// let mut iter = array![1, 2, 3, 4 => 4].into_iter();
+	///
+	/// iter.next();
+	/// iter.as_mut_slice()[0] = 5;
+	/// assert_eq!(iter.next(), Some(5));
+	/// ```
+	#[inline]
+	pub const fn as_mut_slice(&mut self) -> &mut [T] {
+		unsafe {
+			// safety: elements `cur..end` are always initialized. they're only
+			// read out by moving `cur` or `end` past them.
+			let ptr = (&mut self.inner as *mut core::mem::MaybeUninit<T> as *mut T).add(self.cur);
+			core::slice::from_raw_parts_mut(ptr, self.end - self.cur)
+		}
+	}
+}
+
+impl<const N: usize, T> Drop for IntoIter<N, T> {
+	fn drop(&mut self) {
+		while self.cur != self.end {
+			unsafe {
+				self.inner.get_unchecked_mut(self.cur).assume_init_drop();
+				self.cur += 1;
+			}
+		}
+	}
+}
+
+impl<const N: usize, T> Iterator for IntoIter<N, T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.cur == self.end {
+			return None;
+		}
+		unsafe {
+			let out = self.inner.get_unchecked(self.cur).assume_init_read();
+			self.cur += 1;
+			Some(out)
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end - self.cur;
+		(len, Some(len))
+	}
+}
+
+impl<const N: usize, T> DoubleEndedIterator for IntoIter<N, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.cur == self.end {
+			return None;
+		}
+		unsafe {
+			self.end -= 1;
+			Some(self.inner.get_unchecked(self.end).assume_init_read())
+		}
+	}
+}
+
+impl<const N: usize, T> ExactSizeIterator for IntoIter<N, T> {}
+
+impl<const N: usize, T> core::iter::FusedIterator for IntoIter<N, T> {}
+
+impl<const N: usize, T> IntoIterator for Array<N, T> {
+	type IntoIter = IntoIter<N, T>;
+	type Item = T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		let (buf, len) = self.into_parts_len();
+		IntoIter {
+			inner: buf,
+			cur: 0,
+			end: len,
+		}
+	}
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a Array<N, T> {
+	type IntoIter = core::slice::Iter<'a, T>;
+	type Item = &'a T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_slice().iter()
+	}
+}
+
+impl<'a, const N: usize, T> IntoIterator for &'a mut Array<N, T> {
+	type IntoIter = core::slice::IterMut<'a, T>;
+	type Item = &'a mut T;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.as_mut_slice().iter_mut()
+	}
+}
+
+/// draining iterator for [`Array`]. see [`Array::drain()`].
+pub struct Drain<'a, const N: usize, T> {
+	array: &'a mut Array<N, T>,
+	cur: usize,
+	end: usize,
+	tail: usize,
+	tail_len: usize,
+}
+
+impl<const N: usize, T> Drop for Drain<'_, N, T> {
+	fn drop(&mut self) {
+		// moves the tail back even if dropping an element panics
+		struct Guard<'r, 'a, const N: usize, T>(&'r mut Drain<'a, N, T>);
+
+		impl<const N: usize, T> Drop for Guard<'_, '_, N, T> {
+			fn drop(&mut self) {
+				let drain = &mut *self.0;
+				let start = drain.array.len();
+
+				unsafe {
+					// safety: the tail is initialized, and `start..` is free
+					let ptr = drain.array.as_mut_ptr();
+					core::ptr::copy(ptr.add(drain.tail), ptr.add(start), drain.tail_len);
+					drain.array.set_len(start + drain.tail_len);
+				}
+			}
+		}
+
+		let remaining = unsafe {
+			core::ptr::slice_from_raw_parts_mut(self.array.as_mut_ptr().add(self.cur), self.end - self.cur)
+		};
+		self.cur = self.end;
+
+		let _guard = Guard(self);
+
+		unsafe {
+			// safety: these elements were never yielded
+			core::ptr::drop_in_place(remaining);
+		}
+	}
+}
+
+impl<const N: usize, T> Iterator for Drain<'_, N, T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.cur == self.end {
+			return None;
+		}
+		unsafe {
+			let out = core::ptr::read(self.array.as_ptr().add(self.cur));
+			self.cur += 1;
+			Some(out)
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end - self.cur;
+		(len, Some(len))
+	}
+}
+
+impl<const N: usize, T> DoubleEndedIterator for Drain<'_, N, T> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.cur == self.end {
+			return None;
+		}
+		unsafe {
+			self.end -= 1;
+			Some(core::ptr::read(self.array.as_ptr().add(self.end)))
+		}
+	}
+}
+
+impl<const N: usize, T> ExactSizeIterator for Drain<'_, N, T> {}
+
+impl<const N: usize, T> core::iter::FusedIterator for Drain<'_, N, T> {}
+
+/// filtering iterator for [`Array`]. see [`Array::extract_if()`].
+pub struct ExtractIf<'a, const N: usize, T, F: FnMut(&mut T) -> bool> {
+	array: &'a mut Array<N, T>,
+	filter: F,
+	/// the next element to visit.
+	cur: usize,
+	/// how many elements have been removed, ie; how far the kept elements
+	/// are shifted down.
+	removed: usize,
+	len: usize,
+	/// set while `filter` runs, so a panicking filter isn't called again.
+	panicked: bool,
+}
+
+impl<const N: usize, T, F: FnMut(&mut T) -> bool> Drop for ExtractIf<'_, N, T, F> {
+	fn drop(&mut self) {
+		// moves the unvisited elements back even if `filter` or dropping an
+		// element panics
+		struct Guard<'r, 'a, const N: usize, T, F: FnMut(&mut T) -> bool>(&'r mut ExtractIf<'a, N, T, F>);
+
+		impl<const N: usize, T, F: FnMut(&mut T) -> bool> Drop for Guard<'_, '_, N, T, F> {
+			fn drop(&mut self) {
+				let extract = &mut *self.0;
+
+				unsafe {
+					// safety: `cur..len` is initialized, and `cur - removed..cur` is free
+					let ptr = extract.array.as_mut_ptr();
+					if extract.removed > 0 {
+						core::ptr::copy(
+							ptr.add(extract.cur),
+							ptr.add(extract.cur - extract.removed),
+							extract.len - extract.cur,
+						);
+					}
+					extract.array.set_len(extract.len - extract.removed);
+				}
+			}
+		}
+
+		let guard = Guard(self);
+
+		if !guard.0.panicked {
+			guard.0.for_each(drop);
+		}
+	}
+}
+
+impl<const N: usize, T, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'_, N, T, F> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.cur < self.len {
+			unsafe {
+				// safety: `cur` is in bounds and initialized
+				let ptr = self.array.as_mut_ptr().add(self.cur);
+
+				self.panicked = true;
+				let matched = (self.filter)(&mut *ptr);
+				self.panicked = false;
+
+				self.cur += 1;
+
+				if matched {
+					self.removed += 1;
+					return Some(core::ptr::read(ptr));
+				}
+
+				if self.removed > 0 {
+					// safety: the slot `removed` elements back was already moved out of
+					core::ptr::copy_nonoverlapping(ptr, ptr.sub(self.removed), 1);
+				}
+			}
+		}
+
+		None
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, Some(self.len - self.cur))
+	}
+}
+
+impl<const N: usize, T, F: FnMut(&mut T) -> bool> core::iter::FusedIterator for ExtractIf<'_, N, T, F> {}
+
+/// collects elements into an array.
+///
+/// by default, elements that don't fit in the array are discarded. with the
+/// `strict-collect` feature enabled, this panics instead.
+impl<const N: usize, T> FromIterator<T> for Array<N, T> {
+	#[cfg(not(feature = "strict-collect"))]
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+
+	#[cfg(feature = "strict-collect")]
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut out = Self::new();
+		for i in iter {
+			if out.push_checked(i).is_err() {
+				panic!("collect exceeds capacity");
+			}
+		}
+		out
+	}
+}
+
+
+impl<const N: usize, const M: usize, T: PartialOrd> PartialOrd<Array<M, T>> for Array<N, T> {
+	fn partial_cmp(&self, other: &Array<M, T>) -> Option<core::cmp::Ordering> {
+		PartialOrd::partial_cmp(self.as_slice(), other.as_slice())
+	}
+}
+
+impl<const N: usize, T: Eq> Eq for Array<N, T> {}
+
+impl<const N: usize, T: Ord> Ord for Array<N, T> {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		Ord::cmp(self.as_slice(), other.as_slice())
+	}
+}
+
+impl<const N: usize, const M: usize, T: PartialEq> PartialEq<Array<M, T>> for Array<N, T> {
+	fn eq(&self, other: &Array<M, T>) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+impl<const N: usize, T: PartialEq> PartialEq<&[T]> for Array<N, T> {
+	fn eq(&self, other: &&[T]) -> bool {
+		PartialEq::eq(self.as_slice(), *other)
+	}
+}
+
+impl<const N: usize, T: PartialEq> PartialEq<&mut [T]> for Array<N, T> {
+	fn eq(&self, other: &&mut [T]) -> bool {
+		PartialEq::eq(self.as_slice(), *other)
+	}
+}
+
+impl<const N: usize, const M: usize, T: PartialEq> PartialEq<[T; M]> for Array<N, T> {
+	fn eq(&self, other: &[T; M]) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+impl<const N: usize, const M: usize, T: PartialEq> PartialEq<&[T; M]> for Array<N, T> {
+	fn eq(&self, other: &&[T; M]) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize, T: PartialEq> PartialEq<std::vec::Vec<T>> for Array<N, T> {
+	fn eq(&self, other: &std::vec::Vec<T>) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize, T: PartialEq> PartialEq<Array<N, T>> for std::vec::Vec<T> {
+	fn eq(&self, other: &Array<N, T>) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+impl<const N: usize, T: core::hash::Hash> core::hash::Hash for Array<N, T> {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		core::hash::Hash::hash(self.as_slice(), state)
+	}
+}
+
+impl<const N: usize, T: core::fmt::Debug> core::fmt::Debug for Array<N, T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		core::fmt::Debug::fmt(self.as_slice(), f)
+	}
+}
+
+/// appends the utf-8 bytes of each string. a string that doesn't fit is
+/// rejected whole, so a char is never left half-written.
+impl<const N: usize> core::fmt::Write for Array<N, u8> {
+	fn write_str(&mut self, s: &str) -> core::fmt::Result {
+		self.try_extend_from_slice(s.as_bytes()).map_err(|()| core::fmt::Error)
+	}
+}
+
+/// appends the chars of each string. a string that doesn't fit is rejected
+/// whole.
+impl<const N: usize> core::fmt::Write for Array<N, char> {
+	fn write_str(&mut self, s: &str) -> core::fmt::Result {
+		self.push_str(s).map_err(|()| core::fmt::Error)
+	}
+}
+
+/// serializes the elements as a sequence.
+#[cfg(feature = "serde")]
+impl<const N: usize, T: serde::Serialize> serde::Serialize for Array<N, T> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.as_slice())
+	}
+}
+
+/// deserializes a sequence, failing if it has more elements than the capacity.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Array<N, T> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct Visitor<const N: usize, T>(core::marker::PhantomData<T>);
+
+		impl<'de, const N: usize, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for Visitor<N, T> {
+			type Value = Array<N, T>;
+
+			fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+				write!(f, "a sequence of at most {N} elements")
+			}
+
+			fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				// already deserialized elements are dropped with `out` on error
+				let mut out = Array::new();
+
+				while let Some(element) = seq.next_element()? {
+					if out.push_checked(element).is_err() {
+						return Err(serde::de::Error::invalid_length(N + 1, &self));
+					}
+				}
+
+				Ok(out)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+	}
+}
+
+
+/// create an [`Array`].
+///
+/// like `vec!`, `array!` has similar syntax as Rust array expressions, with
+/// the addition of allowing one to specify the capacity of the `Array`
+/// by appending an `=>`:
+///
+/// ```
+/// # use nyarray::array;
+/// let array = array![1, 2, 3 => 6]; // capacity of 6 elements
+/// assert_eq!(array[0], 1);
+/// assert_eq!(array[1], 2);
+/// assert_eq!(array[2], 3);
+/// ```
+///
+/// the capacity can be any constant expression, not just a literal:
+///
+/// ```
+/// # use nyarray::array;
+/// const CAP: usize = 8;
+///
+/// let array = array![0u8; 4 => CAP];
+/// assert_eq!(array.capacity(), 8);
+///
+/// let array = array![1, 2, 3 => CAP * 2];
+/// assert_eq!(array.capacity(), 16);
+/// ```
+#[macro_export]
+macro_rules! array {
+	() => {
+		$crate::array::Array::new()
+	};
+	(=> $cap:expr) => {
+		$crate::array::Array::<{ $cap }, _>::new()
+	};
+	($elem:expr; $n:expr) => {
+		$crate::array::from_elem($elem, $n)
+	};
+	($elem:expr; $n:expr => $cap:expr) => {
+		$crate::array::from_elem::<{ $cap }, _>($elem, $n)
+	};
+	($($x:expr),+ $(,)?) => {
+		$crate::array::Array::from_parts([$($x),+])
+	};
+	($($x:expr),+ $(,)? => $cap:expr) => {
+		$crate::array::Array::<{ $cap }, _>::from_parts([$($x),+])
+	};
+}
+
+
+#[cfg(test)]
+mod test {
+	extern crate std;
+
+	#[test]
+	fn test_drop() {
+		static mut NUM: u32 = 0;
+
+		struct Box<T> {
+			_inner: std::boxed::Box<T>,
+		}
+		impl<T> Box<T> {
+			fn new(inner: T) -> Self {
+				Self {
+					_inner: std::boxed::Box::new(inner),
+				}
+			}
+		}
+		impl<T> Drop for Box<T> {
+			fn drop(&mut self) {
+				unsafe {
+					NUM += 1;
+				}
+			}
+		}
+
+		let array = array![Box::new(1), Box::new(2), Box::new(3) => 4];
+
+		drop(array);
+
+		assert_eq!(unsafe { NUM }, 3);
+
+		let array = array![Box::new(1), Box::new(2), Box::new(3) => 4];
+		let mut iter = array.into_iter();
+		iter.next();
+
+		drop(iter);
+
+		assert_eq!(unsafe { NUM }, 6);
+	}
 
-impl<const N: usize, T> core::borrow::BorrowMut<[T]> for Array<N, T> {
-	fn borrow_mut(&mut self) -> &mut [T] {
-		self.as_mut_slice()
+	#[test]
+	#[cfg(feature = "strict-collect")]
+	#[should_panic]
+	fn test_strict_collect() {
+		let _: crate::array::Array<4, i32> = (0..5).collect();
 	}
-}
 
-impl<const N: usize, T> core::ops::Deref for Array<N, T> {
-	type Target = [T];
-	fn deref(&self) -> &Self::Target {
-		self.as_slice()
+	#[test]
+	fn test_extend_truncates() {
+		let mut array = array![1, 2 => 4];
+		array.extend([3, 4, 5, 6]);
+		assert_eq!(array, [1, 2, 3, 4]);
+
+		// an iterator reporting a lower bound larger than what it yields
+		struct Liar(bool);
+		impl Iterator for Liar {
+			type Item = i32;
+			fn next(&mut self) -> Option<i32> {
+				core::mem::replace(&mut self.0, false).then_some(3)
+			}
+			fn size_hint(&self) -> (usize, Option<usize>) {
+				(10, None)
+			}
+		}
+
+		let mut array = array![1, 2 => 4];
+		array.extend(Liar(true));
+		assert_eq!(array, [1, 2, 3]);
+
+		let mut array = array![1 => 4];
+		array.extend(&[2, 3, 4, 5, 6]);
+		assert_eq!(array, [1, 2, 3, 4]);
+
+		let mut array = array![1 => 4];
+		array.extend((2..).take_while(|x| *x < 10));
+		assert_eq!(array, [1, 2, 3, 4]);
 	}
-}
 
-impl<const N: usize, T> core::ops::DerefMut for Array<N, T> {
-	fn deref_mut(&mut self) -> &mut Self::Target {
-		self.as_mut_slice()
+	#[test]
+	fn test_full_with_panic() {
+		let drops = crate::test::Counter::new();
+
+		let mut made = 0;
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			crate::array::Array::<4, _>::full_with(|| {
+				if made == 3 {
+					panic!();
+				}
+				made += 1;
+				drops.counted(made)
+			})
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(drops.drops(), 3);
+
+		let mut made = 0;
+		let array = crate::array::Array::<4, _>::full_with(|| {
+			made += 1;
+			drops.counted(made)
+		});
+		assert!(array.iter().map(|x| x.0).eq(1..=4));
 	}
-}
 
-impl<const N: usize, T, I: core::slice::SliceIndex<[T]>> core::ops::Index<I> for Array<N, T> {
-	type Output = I::Output;
-	fn index(&self, index: I) -> &Self::Output {
-		core::ops::Index::index(self.as_slice(), index)
+	#[test]
+	fn test_extend_from_slice_cloned_panic() {
+		let drops = crate::test::Counter::panic_after_clones(2);
+
+		let src = [0, 1, 2, 3].map(|x| drops.counted(x));
+		let mut array = crate::array::Array::<8, _>::new();
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.extend_from_slice_cloned(&src);
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(array.len(), 2);
+
+		drop(array);
+		assert_eq!(drops.drops(), 2);
 	}
-}
 
-impl<const N: usize, T, I: core::slice::SliceIndex<[T]>> core::ops::IndexMut<I> for Array<N, T> {
-	fn index_mut(&mut self, index: I) -> &mut Self::Output {
-		core::ops::IndexMut::index_mut(self.as_mut_slice(), index)
+	#[test]
+	fn test_remove_indices() {
+		let drops: [_; 8] = core::array::from_fn(|_| crate::test::Counter::new());
+		let counts = || drops.each_ref().map(|x| x.drops());
+
+		let mut array: crate::array::Array<8, _> = (0..8).map(|i| drops[i].counted(i as i32)).collect();
+
+		array.remove_indices(&[0, 3, 4, 7]);
+		assert!(array.iter().map(|x| x.0).eq([1, 2, 5, 6]));
+		assert_eq!(counts(), [1, 0, 0, 1, 1, 0, 0, 1]);
+
+		array.remove_indices(&[]);
+		assert_eq!(array.len(), 4);
+
+		array.remove_indices(&[1, 1]);
+		assert!(array.iter().map(|x| x.0).eq([1, 5, 6]));
+
+		drop(array);
+		assert_eq!(counts(), [1; 8]);
 	}
-}
 
-impl<const N: usize, T> Extend<T> for Array<N, T> {
-	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-		for i in iter {
-			if self.push_checked(i).is_err() {
-				break;
+	#[test]
+	fn test_extend_from_slice_overflow() {
+		let mut array = array![1, 2 => 4];
+
+		assert!(array.try_extend_from_slice(&[3, 4, 5]).is_err());
+		assert_eq!(array, [1, 2]);
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.extend_from_slice(&[3, 4, 5]);
+		}));
+		assert!(result.is_err());
+		assert_eq!(array, [1, 2]);
+
+		array.extend_from_slice(&[3, 4]);
+		assert_eq!(array, [1, 2, 3, 4]);
+		array.extend_from_slice(&[]);
+		assert_eq!(array, [1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn test_try_from_slice() {
+		let slice: &[i32] = &[1, 2, 3];
+
+		let array = crate::array::Array::<4, _>::try_from(slice);
+		assert_eq!(array, Ok(array![1, 2, 3]));
+
+		let array = crate::array::Array::<2, _>::try_from(slice);
+		assert_eq!(array, Err(3));
+	}
+
+	#[test]
+	fn test_try_clone_with_drop() {
+		let drops = crate::test::Counter::new();
+
+		let array: crate::array::Array<4, _> = (0..4).map(|x| drops.counted(x)).collect();
+
+		let clone = array.try_clone_with(|x| if x.0 == 2 { Err(()) } else { Ok(drops.counted(x.0)) });
+		assert!(clone.is_err());
+		assert_eq!(drops.drops(), 2);
+
+		drop(array);
+		assert_eq!(drops.drops(), 6);
+	}
+
+	#[test]
+	fn test_resize_drop() {
+		let drops = crate::test::Counter::new();
+
+		let mut array = crate::array::Array::<8, _>::new();
+
+		array.resize(6, drops.counted(0));
+		assert_eq!(array.len(), 6);
+		assert_eq!(drops.drops(), 0);
+
+		array.resize(2, drops.counted(0));
+		assert_eq!(array.len(), 2);
+		// four truncated, plus the unused `value`
+		assert_eq!(drops.drops(), 5);
+
+		array.resize_with(0, || drops.counted(0));
+		assert_eq!(drops.drops(), 7);
+	}
+
+	#[test]
+	fn test_split_off() {
+		let drops = crate::test::Counter::new();
+
+		let mut array: crate::array::Array<8, _> = (0..5).map(|x| drops.counted(x)).collect();
+
+		let tail = array.split_off(5);
+		assert_eq!(array.len(), 5);
+		assert!(tail.is_empty());
+
+		let tail = array.split_off(2);
+		assert!(array.iter().map(|x| x.0).eq([0, 1]));
+		assert!(tail.iter().map(|x| x.0).eq([2, 3, 4]));
+		assert_eq!(drops.drops(), 0);
+
+		let head = array.split_off(0);
+		assert!(array.is_empty());
+		assert_eq!(head.len(), 2);
+
+		drop(head);
+		drop(tail);
+		drop(array);
+		assert_eq!(drops.drops(), 5);
+	}
+
+	#[test]
+	fn test_swap_remove_returning() {
+		let mut array = array![1, 2, 3, 4, 5 => 8];
+
+		assert_eq!(array.swap_remove_returning(4), Some((5, false)));
+		assert_eq!(array, [1, 2, 3, 4]);
+
+		assert_eq!(array.swap_remove_returning(1), Some((2, true)));
+		assert_eq!(array, [1, 4, 3]);
+
+		assert_eq!(array.swap_remove_returning(3), None);
+		assert_eq!(array, [1, 4, 3]);
+
+		let mut array = array![1 => 1];
+		assert_eq!(array.swap_remove_returning(0), Some((1, false)));
+		assert!(array.is_empty());
+		assert_eq!(array.swap_remove_returning(0), None);
+	}
+
+	#[test]
+	fn test_append() {
+		let drops = crate::test::Counter::new();
+
+		let mut array: crate::array::Array<6, _> = (0..2).map(|x| drops.counted(x)).collect();
+		let mut small: crate::array::Array<3, _> = (2..5).map(|x| drops.counted(x)).collect();
+		let mut large: crate::array::Array<16, _> = (5..7).map(|x| drops.counted(x)).collect();
+
+		array.append(&mut small);
+		assert_eq!(array.len(), 5);
+		assert!(small.is_empty());
+
+		assert!(array.try_append(&mut large).is_err());
+		assert_eq!(array.len(), 5);
+		assert_eq!(large.len(), 2);
+
+		large.pop();
+		assert_eq!(drops.drops(), 1);
+
+		array.append(&mut large);
+		assert!(large.is_empty());
+		assert!(array.iter().map(|x| x.0).eq(0..6));
+
+		drop(small);
+		drop(large);
+		assert_eq!(drops.drops(), 1);
+
+		drop(array);
+		assert_eq!(drops.drops(), 7);
+	}
+
+	#[test]
+	fn test_fill_bytes() {
+		let mut array = crate::array::Array::<4096, u8>::new();
+		array.resize(4000, 0xff);
+
+		array.fill(0);
+		assert_eq!(array.len(), 4000);
+		assert!(array.iter().all(|x| *x == 0));
+		// spare capacity is left alone
+		assert_eq!(array.spare_capacity_mut().len(), 96);
+	}
+
+	#[test]
+	fn test_drain() {
+		let drops = crate::test::Counter::new();
+
+		let make = || -> crate::array::Array<8, _> { (0..6).map(|x| drops.counted(x)).collect() };
+
+		// full range
+		let mut array = make();
+		assert!(array.drain(..).map(|x| x.0).eq(0..6));
+		assert!(array.is_empty());
+		assert_eq!(drops.drops(), 6);
+		drop(array);
+		assert_eq!(drops.drops(), 6);
+
+		// partial range, from both ends
+		let mut array = make();
+		let mut drain = array.drain(1..5);
+		assert_eq!(drain.len(), 4);
+		assert_eq!(drain.next().map(|x| x.0), Some(1));
+		assert_eq!(drain.next_back().map(|x| x.0), Some(4));
+		assert_eq!(drain.len(), 2);
+		drop(drain);
+		assert_eq!(drops.drops(), 10);
+		assert!(array.iter().map(|x| x.0).eq([0, 5]));
+		drop(array);
+		assert_eq!(drops.drops(), 12);
+
+		// early drop
+		let mut array = make();
+		array.drain(2..4);
+		assert_eq!(drops.drops(), 14);
+		assert!(array.iter().map(|x| x.0).eq([0, 1, 4, 5]));
+
+		// empty range
+		array.drain(2..2);
+		assert_eq!(array.len(), 4);
+		drop(array);
+		assert_eq!(drops.drops(), 18);
+	}
+
+	#[test]
+	fn test_drain_panic() {
+		let drops = crate::test::Counter::new();
+
+		let mut array = crate::array::Array::<8, _>::new();
+		array.push(drops.counted(0));
+		array.push(drops.bomb(1));
+		array.push(drops.counted(2));
+		array.push(drops.counted(3));
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.drain(1..3);
+		}));
+		assert!(result.is_err());
+		assert_eq!(drops.drops(), 2);
+		// the tail was still moved back
+		assert!(array.iter().map(|x| x.0).eq([0, 3]));
+
+		drop(array);
+		assert_eq!(drops.drops(), 4);
+	}
+
+	#[test]
+	fn test_dedup_panic() {
+		let drops = crate::test::Counter::new();
+
+		let mut array: crate::array::Array<8, _> = [1, 1, 2, 2, 3, 3].map(|x| drops.counted(x)).into_iter().collect();
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.dedup_by(|a, b| {
+				if a.0 == 3 {
+					panic!("boom");
+				}
+				a.0 == b.0
+			});
+		}));
+		assert!(result.is_err());
+
+		// the two duplicates before the panic are gone, the rest is kept
+		assert_eq!(drops.drops(), 2);
+		assert!(array.iter().map(|x| x.0).eq([1, 2, 3, 3]));
+
+		drop(array);
+		assert_eq!(drops.drops(), 6);
+	}
+
+	#[test]
+	fn test_fill_from_dyn() {
+		let mut iter: std::boxed::Box<dyn Iterator<Item = i32>> = std::boxed::Box::new((0..3).map(|x| x * 10));
+
+		let mut array = crate::array::Array::<8, i32>::new();
+		assert_eq!(array.fill_from_dyn(&mut *iter), 3);
+		assert_eq!(array, [0, 10, 20]);
+		assert_eq!(array.fill_from_dyn(&mut *iter), 0);
+
+		let mut iter: std::boxed::Box<dyn Iterator<Item = i32>> = std::boxed::Box::new(0..);
+
+		let mut array = crate::array::Array::<4, i32>::new();
+		assert_eq!(array.fill_from_dyn(&mut *iter), 4);
+		assert_eq!(array, [0, 1, 2, 3]);
+		assert_eq!(array.fill_from_dyn(&mut *iter), 0);
+		assert_eq!(iter.next(), Some(4));
+	}
+
+	#[test]
+	fn test_swap_ranges() {
+		let mut array: crate::array::Array<8, _> = (0..8).map(|n| "x".repeat(n)).collect();
+		array.swap_ranges(0..4, 4..8);
+		assert!(array.iter().map(|x| x.len()).eq([4, 5, 6, 7, 0, 1, 2, 3]));
+
+		// empty ranges swap nothing
+		array.swap_ranges(2..2, 2..2);
+		assert!(array.iter().map(|x| x.len()).eq([4, 5, 6, 7, 0, 1, 2, 3]));
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.swap_ranges(0..4, 3..7);
+		}));
+		assert!(result.is_err());
+		assert!(array.iter().map(|x| x.len()).eq([4, 5, 6, 7, 0, 1, 2, 3]));
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.swap_ranges(0..2, 4..7);
+		}));
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_from_const_slice() {
+		static TABLE: crate::array::Array<16, u8> = crate::array::Array::from_const_slice(b"0123456789abcdef");
+		const EMPTY: crate::array::Array<4, u8> = crate::array::Array::from_const_slice(&[]);
+
+		assert_eq!(TABLE.len(), 16);
+		assert_eq!(TABLE[10], b'a');
+		assert!(EMPTY.is_empty());
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_serde() {
+		let array = array![1, 2, 3 => 4];
+
+		let json = serde_json::to_string(&array).unwrap();
+		assert_eq!(json, "[1,2,3]");
+
+		let back: crate::array::Array<4, i32> = serde_json::from_str(&json).unwrap();
+		assert_eq!(back, array);
+
+		let back: crate::array::Array<3, i32> = serde_json::from_str(&json).unwrap();
+		assert_eq!(back, array);
+
+		let result = serde_json::from_str::<crate::array::Array<2, i32>>(&json);
+		assert!(result.is_err());
+
+		// an error midway drops what was already deserialized
+		let result = serde_json::from_str::<crate::array::Array<4, std::string::String>>(r#"["a", "b", 3]"#);
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_chunks_padded() {
+		let array: crate::array::Array<5, u8> = array![1, 2, 3, 4, 5];
+		assert!(array.chunks_padded::<4>(0xff).eq([[1, 2, 3, 4], [5, 0xff, 0xff, 0xff]]));
+
+		let array: crate::array::Array<8, u8> = array![1, 2, 3, 4];
+		assert!(array.chunks_padded::<4>(0xff).eq([[1, 2, 3, 4]]));
+		assert!(array.chunks_padded::<2>(0xff).eq([[1, 2], [3, 4]]));
+
+		let array = crate::array::Array::<8, u8>::new();
+		assert_eq!(array.chunks_padded::<4>(0).count(), 0);
+	}
+
+	#[test]
+	fn test_retain_parallel() {
+		let mut ids: crate::array::Array<6, u32> = (0..6).collect();
+		let mut names: crate::array::Array<6, _> = ["a", "b", "c", "d", "e", "f"].map(std::string::String::from).into_iter().collect();
+
+		crate::array::retain_parallel(&mut ids, &mut names, |id, name| id % 2 == 0 && name != "e");
+		assert_eq!(ids, [0, 2]);
+		assert_eq!(names.as_slice(), ["a", "c"]);
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			crate::array::retain_parallel(&mut ids, &mut names, |id, _| {
+				if *id == 2 {
+					panic!("boom");
+				}
+				false
+			});
+		}));
+		assert!(result.is_err());
+		assert_eq!(ids, [0, 2]);
+		assert_eq!(names.as_slice(), ["a", "c"]);
+	}
+
+	#[test]
+	fn test_clone_from() {
+		let drops = crate::test::Counter::new();
+
+		let short: crate::array::Array<8, _> = (0..2).map(|x| drops.counted(x)).collect();
+		let long: crate::array::Array<8, _> = (10..16).map(|x| drops.counted(x)).collect();
+
+		let mut array: crate::array::Array<8, _> = (20..24).map(|x| drops.counted(x)).collect();
+
+		// shrinking drops only the excess
+		array.clone_from(&short);
+		assert!(array.iter().map(|x| x.0).eq(0..2));
+		assert_eq!(drops.drops(), 2);
+		assert_eq!(drops.clones(), 0);
+
+		// growing clones only the new elements
+		array.clone_from(&long);
+		assert!(array.iter().map(|x| x.0).eq(10..16));
+		assert_eq!(drops.drops(), 2);
+		assert_eq!(drops.clones(), 4);
+	}
+
+	#[test]
+	fn test_sort_stable() {
+		// small lcg, so the test is deterministic
+		let mut seed = 0x2545_f491u32;
+		let mut random = move || {
+			seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			seed >> 16
+		};
+
+		#[derive(Debug, Clone, Copy)]
+		struct Tagged {
+			key: u32,
+			tag: usize,
+		}
+		impl PartialEq for Tagged {
+			fn eq(&self, other: &Self) -> bool {
+				self.key == other.key
+			}
+		}
+		impl Eq for Tagged {}
+		impl PartialOrd for Tagged {
+			fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+		impl Ord for Tagged {
+			fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+				self.key.cmp(&other.key)
+			}
+		}
+
+		for len in [0, 1, 2, 3, 15, 16, 17, 31, 64, 100, 255, 256] {
+			for keys in [4, 64, 1 << 16] {
+				let mut array: crate::array::Array<256, _> = (0..len)
+					.map(|tag| Tagged { key: random() % keys, tag })
+					.collect();
+
+				let mut expected = array.clone();
+				expected.as_mut_slice().sort_unstable_by_key(|x| (x.key, x.tag));
+
+				array.sort_stable();
+
+				assert!(
+					array.iter().map(|x| (x.key, x.tag)).eq(expected.iter().map(|x| (x.key, x.tag))),
+					"len {len}, keys {keys}",
+				);
 			}
 		}
+
+		let mut array: crate::array::Array<64, u32> = (0..64).rev().collect();
+		array.sort_stable();
+		assert!(array.iter().copied().eq(0..64));
+	}
+
+	#[test]
+	fn test_find_subslice() {
+		let array = array![1, 2, 3, 1, 2, 4 => 8];
+
+		assert_eq!(array.find_subslice(&[1, 2]), Some(0));
+		assert_eq!(array.find_subslice(&[1, 2, 4]), Some(3));
+		assert_eq!(array.find_subslice(&[2, 4]), Some(4));
+		assert_eq!(array.find_subslice(&[1, 2, 3, 1, 2, 4]), Some(0));
+		assert_eq!(array.find_subslice(&[4, 1]), None);
+		assert_eq!(array.find_subslice(&[1, 2, 3, 1, 2, 4, 5]), None);
+		assert_eq!(array.find_subslice(&[]), Some(0));
+
+		let empty = crate::array::Array::<8, i32>::new();
+		assert_eq!(empty.find_subslice(&[1]), None);
+		assert_eq!(empty.find_subslice(&[]), Some(0));
+	}
+
+	#[test]
+	fn test_split_on() {
+		fn split<'a>(array: &'a crate::array::Array<16, u8>, delim: &'a [u8]) -> std::vec::Vec<&'a [u8]> {
+			array.split_on(delim).collect()
+		}
+
+		let array: crate::array::Array<16, u8> = b"ab::cd::ef".iter().copied().collect();
+		assert_eq!(split(&array, b"::"), [&b"ab"[..], b"cd", b"ef"]);
+
+		let array: crate::array::Array<16, u8> = b"::ab::::cd::".iter().copied().collect();
+		assert_eq!(split(&array, b"::"), [&b""[..], b"ab", b"", b"cd", b""]);
+
+		// delimiters don't overlap
+		let array: crate::array::Array<16, u8> = b"a:::b".iter().copied().collect();
+		assert_eq!(split(&array, b"::"), [&b"a"[..], b":b"]);
+
+		let array: crate::array::Array<16, u8> = b"abc".iter().copied().collect();
+		assert_eq!(split(&array, b"::"), [&b"abc"[..]]);
+
+		let array = crate::array::Array::<16, u8>::new();
+		assert_eq!(split(&array, b"::"), [&b""[..]]);
+	}
+
+	#[test]
+	fn test_commit() {
+		let mut array = crate::array::Array::<4, u8>::new();
+
+		unsafe {
+			// committing the current length does nothing
+			array.commit(0);
+		}
+		assert!(array.is_empty());
+
+		for (i, slot) in array.spare_capacity_mut().iter_mut().enumerate() {
+			slot.write(i as u8);
+		}
+		unsafe {
+			array.commit(3);
+		}
+		assert_eq!(array, [0, 1, 2]);
+
+		unsafe {
+			array.commit(4);
+		}
+		assert_eq!(array, [0, 1, 2, 3]);
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+			array.commit(5);
+		}));
+		assert!(result.is_err());
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| unsafe {
+			array.commit(2);
+		}));
+		assert!(result.is_err());
+		assert_eq!(array, [0, 1, 2, 3]);
+	}
+
+	#[test]
+	fn test_retain() {
+		let mut array = array![1, 2, 3, 4, 5 => 8];
+		array.retain(|x| *x != 1);
+		assert_eq!(array, [2, 3, 4, 5]);
+		array.retain(|x| *x != 3);
+		assert_eq!(array, [2, 4, 5]);
+		array.retain(|x| *x != 5);
+		assert_eq!(array, [2, 4]);
+		array.retain(|_| false);
+		assert!(array.is_empty());
+	}
+
+	#[test]
+	fn test_retain_panic() {
+		let drops: [_; 6] = core::array::from_fn(|_| crate::test::Counter::new());
+		let counts = || drops.each_ref().map(|x| x.drops());
+
+		let mut array: crate::array::Array<8, _> = (0..6).map(|i| drops[i].counted(i as i32)).collect();
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.retain(|x| {
+				if x.0 == 3 {
+					panic!();
+				}
+				x.0 % 2 == 0
+			});
+		}));
+
+		assert!(result.is_err());
+		assert!(array.iter().map(|x| x.0).eq([0, 2, 3, 4, 5]));
+		assert_eq!(counts(), [0, 1, 0, 0, 0, 0]);
+
+		drop(array);
+		assert_eq!(counts(), [1; 6]);
+	}
+
+	#[test]
+	fn test_elementwise() {
+		let a = array![1.0f32, 2.0, 3.0 => 3];
+		let b = array![4.0f32, 5.0, 6.0 => 3];
+
+		assert_eq!(a.clone() + b.clone(), [5.0, 7.0, 9.0]);
+		assert_eq!(b.clone() - a.clone(), [3.0, 3.0, 3.0]);
+		assert_eq!(a.clone() * b.clone(), [4.0, 10.0, 18.0]);
+
+		let mut c = a.clone();
+		c += b.clone();
+		assert_eq!(c, [5.0, 7.0, 9.0]);
+		c -= a.clone();
+		assert_eq!(c, b);
+		c *= a;
+		assert_eq!(c, [4.0, 10.0, 18.0]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_elementwise_mismatch() {
+		let _ = array![1, 2, 3 => 4] + array![1, 2 => 4];
+	}
+
+	#[test]
+	fn test_moving_average() {
+		let array = array![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0 => 8];
+		assert_eq!(array.moving_average::<3>(), [2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+		assert_eq!(array.moving_average::<8>(), [4.5]);
+		assert!(array.moving_average::<9>().is_empty());
+
+		let array = array![1i32, 2, 4, 8 => 4];
+		assert_eq!(array.moving_average::<2>(), [1, 3, 6]);
 	}
-}
 
-impl<'a, const N: usize, T: Copy> Extend<&'a T> for Array<N, T> {
-	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
-		for i in iter {
-			if self.push_checked(*i).is_err() {
-				break;
+	#[test]
+	fn test_moving_average_int_range() {
+		assert_eq!(array![255u8, 255, 255, 254 => 4].moving_average::<3>(), [255, 254]);
+		assert_eq!(array![u64::MAX, u64::MAX, 1 => 4].moving_average::<2>(), [u64::MAX, u64::MAX / 2 + 1]);
+		assert_eq!(array![u128::MAX, u128::MAX => 4].moving_average::<2>(), [u128::MAX]);
+		assert_eq!(array![200u8, 201, 202 => 4].moving_average::<255>(), []);
+		assert_eq!(crate::array::Array::<255, u8>::full_with(|| 254).moving_average::<255>(), [254]);
+
+		assert_eq!(array![-128i8, -128, -128 => 4].moving_average::<2>(), [-128, -128]);
+		assert_eq!(array![127i8, 127, -128, -128 => 4].moving_average::<2>(), [127, 0, -128]);
+		assert_eq!(array![-7i8, 2 => 4].moving_average::<2>(), [-2]);
+		assert_eq!(array![7i8, -2 => 4].moving_average::<2>(), [2]);
+		assert_eq!(array![i128::MIN, i128::MIN, i128::MAX => 4].moving_average::<3>(), [-(i128::MAX / 3) - 1]);
+
+		// every combination of small values matches the exact average
+		for a in -128i8..=127 {
+			for b in [-128i8, -100, -3, -1, 0, 1, 2, 99, 127] {
+				let window = array![a, b, a => 4];
+				let exact = (a as i32 * 2 + b as i32) / 3;
+				assert_eq!(window.moving_average::<3>(), [exact as i8]);
 			}
 		}
 	}
-}
 
+	#[test]
+	#[should_panic]
+	fn test_moving_average_window_range() {
+		let _ = array![1i8, 2, 3 => 4].moving_average::<128>();
+	}
 
-#[doc(hidden)]
-pub fn from_elem<const N: usize, T: Clone>(elem: T, n: usize) -> Array<N, T> {
-	let mut array = Array::new();
-	for _ in 0..n {
-		array.push(elem.clone());
+	#[test]
+	fn test_dot() {
+		let a = array![1.0f32, 2.0, 3.0 => 3];
+		let b = array![4.0f32, -5.0, 6.0 => 3];
+		assert_eq!(a.dot(&b), 12.0);
+		assert_eq!(a.norm_squared(), 14.0);
+
+		let empty = crate::array::Array::<3, f32>::new();
+		assert_eq!(empty.dot(&empty), 0.0);
+
+		let a = array![3u8, 4 => 4];
+		assert_eq!(a.norm_squared(), 25);
 	}
-	array
-}
 
+	#[test]
+	#[should_panic]
+	#[cfg(debug_assertions)]
+	fn test_norm_squared_overflow() {
+		let _ = array![16u8 => 4].norm_squared();
+	}
 
-/// iterator for [`Array`].
-pub struct IntoIter<const N: usize, T> {
-	inner: [core::mem::MaybeUninit<T>; N],
-	cur: usize,
-	end: usize,
-}
+	#[test]
+	#[should_panic]
+	fn test_dot_mismatch() {
+		let _ = array![1i32, 2, 3 => 4].dot(&array![1, 2 => 4]);
+	}
 
-impl<const N: usize, T> Drop for IntoIter<N, T> {
-	fn drop(&mut self) {
-		while self.cur != self.end {
-			unsafe {
-				self.inner.get_unchecked_mut(self.cur).assume_init_drop();
-				self.cur += 1;
+	#[test]
+	fn test_ord() {
+		let cases: [&[i32]; 8] = [
+			&[],
+			&[0],
+			&[1],
+			&[1, 2],
+			&[1, 2, 3],
+			&[1, 3],
+			&[2],
+			&[4, 3, 2, 1],
+		];
+
+		for a in cases {
+			for b in cases {
+				let x = a.iter().copied().collect::<crate::array::Array<4, _>>();
+				let y = b.iter().copied().collect::<crate::array::Array<8, _>>();
+
+				assert_eq!(x.partial_cmp(&y), a.partial_cmp(b));
+				assert_eq!(x.cmp(&x.clone()), core::cmp::Ordering::Equal);
+
+				let z = b.iter().copied().collect::<crate::array::Array<4, _>>();
+				assert_eq!(x.cmp(&z), a.cmp(b));
+				assert_eq!(x.cmp(&z), std::vec::Vec::from(a).cmp(&std::vec::Vec::from(b)));
 			}
 		}
 	}
-}
-
-impl<const N: usize, T> Iterator for IntoIter<N, T> {
-	type Item = T;
 
-	fn next(&mut self) -> Option<Self::Item> {
-		if self.cur == self.end {
-			return None;
-		}
-		unsafe {
-			let out = self.inner.get_unchecked(self.cur).assume_init_read();
-			self.cur += 1;
-			Some(out)
+	#[test]
+	fn test_hash() {
+		fn hash<H: core::hash::Hash + ?Sized>(value: &H) -> u64 {
+			use core::hash::Hasher;
+			let mut hasher = std::collections::hash_map::DefaultHasher::new();
+			value.hash(&mut hasher);
+			hasher.finish()
 		}
+
+		let a = array![1, 2, 3 => 4];
+		let b = array![1, 2, 3 => 8];
+
+		assert_eq!(hash(&a), hash(&b));
+		assert_eq!(hash(&a), hash(&[1, 2, 3][..]));
+		assert_ne!(hash(&a), hash(&array![1, 2 => 4]));
 	}
-}
 
-impl<const N: usize, T> DoubleEndedIterator for IntoIter<N, T> {
-	fn next_back(&mut self) -> Option<Self::Item> {
-		if self.cur == self.end {
-			return None;
-		}
-		unsafe {
-			self.end -= 1;
-			Some(self.inner.get_unchecked(self.end).assume_init_read())
-		}
+	#[test]
+	fn test_iter() {
+		let array = array![std::boxed::Box::new(1) => 4];
+		let _ = array.iter().cloned().collect::<crate::array::Array<4, _>>();
 	}
-}
 
-impl<const N: usize, T> IntoIterator for Array<N, T> {
-	type IntoIter = IntoIter<N, T>;
-	type Item = T;
+	#[test]
+	fn test_into_iter_len() {
+		let mut iter = array![1, 2, 3, 4, 5 => 8].into_iter();
+		assert_eq!(iter.len(), 5);
+		assert_eq!(iter.size_hint(), (5, Some(5)));
 
-	fn into_iter(self) -> Self::IntoIter {
-		let (buf, len) = self.into_parts_len();
-		IntoIter {
-			inner: buf,
-			cur: 0,
-			end: len,
-		}
+		iter.next();
+		assert_eq!(iter.len(), 4);
+		iter.next_back();
+		assert_eq!(iter.len(), 3);
+		iter.next_back();
+		iter.next();
+		assert_eq!(iter.len(), 1);
+		iter.next();
+		assert_eq!(iter.len(), 0);
+		assert_eq!(iter.size_hint(), (0, Some(0)));
+
+		iter.next();
+		assert_eq!(iter.len(), 0);
 	}
-}
 
-impl<'a, const N: usize, T> IntoIterator for &'a Array<N, T> {
-	type IntoIter = core::slice::Iter<'a, T>;
-	type Item = &'a T;
+	#[test]
+	fn test_into_iter_as_slice() {
+		let mut iter = array![1, 2, 3, 4, 5 => 8].into_iter();
+		assert_eq!(iter.as_slice(), [1, 2, 3, 4, 5]);
 
-	fn into_iter(self) -> Self::IntoIter {
-		self.as_slice().iter()
+		iter.next();
+		assert_eq!(iter.as_slice(), [2, 3, 4, 5]);
+		iter.next_back();
+		assert_eq!(iter.as_slice(), [2, 3, 4]);
+
+		iter.as_mut_slice().reverse();
+		assert_eq!(iter.as_slice(), [4, 3, 2]);
+		assert_eq!(iter.next_back(), Some(2));
+		assert_eq!(iter.as_slice(), [4, 3]);
 	}
-}
 
-impl<'a, const N: usize, T> IntoIterator for &'a mut Array<N, T> {
-	type IntoIter = core::slice::IterMut<'a, T>;
-	type Item = &'a mut T;
+	#[test]
+	fn test_into_iter_exhausted() {
+		let drops = crate::test::Counter::new();
 
-	fn into_iter(self) -> Self::IntoIter {
-		self.as_mut_slice().iter_mut()
+		let array: crate::array::Array<8, _> = (0..5).map(|x| drops.counted(x)).collect();
+		let mut iter = array.into_iter();
+
+		// meet in the middle
+		assert_eq!(iter.next().map(|x| x.0), Some(0));
+		assert_eq!(iter.next_back().map(|x| x.0), Some(4));
+		assert_eq!(iter.next().map(|x| x.0), Some(1));
+		assert_eq!(iter.next_back().map(|x| x.0), Some(3));
+		assert_eq!(iter.next_back().map(|x| x.0), Some(2));
+		assert_eq!(drops.drops(), 5);
+
+		assert!(iter.next().is_none());
+		assert!(iter.next_back().is_none());
+		assert!(iter.next().is_none());
+		assert!(iter.as_slice().is_empty());
+		assert!(iter.as_mut_slice().is_empty());
+
+		drop(iter);
+		assert_eq!(drops.drops(), 5);
+
+		let iter = crate::array::Array::<8, crate::test::Counted>::new().into_iter();
+		drop(iter);
+		assert_eq!(drops.drops(), 5);
 	}
-}
 
-impl<const N: usize, T> FromIterator<T> for Array<N, T> {
-	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-		let mut out = Self::new();
-		out.extend(iter);
-		out
+	#[test]
+	fn test_fmt_write() {
+		use core::fmt::Write;
+
+		let mut bytes = crate::array::Array::<8, u8>::new();
+		write!(bytes, "n={}", 42).unwrap();
+		assert_eq!(bytes, *b"n=42");
+
+		let mut chars = crate::array::Array::<8, char>::new();
+		write!(chars, "{}~", -7).unwrap();
+		assert_eq!(chars, ['-', '7', '~']);
 	}
-}
 
+	#[test]
+	fn test_fmt_write_full() {
+		use core::fmt::Write;
+
+		let mut bytes = crate::array::Array::<4, u8>::new();
+		bytes.write_str("ab").unwrap();
+		// 'é' is two bytes, so the whole string is rejected
+		assert!(bytes.write_str("cé").is_err());
+		assert_eq!(bytes, *b"ab");
+		assert!(write!(bytes, "{}", 12345).is_err());
+		assert_eq!(bytes, *b"ab");
+		bytes.write_str("é").unwrap();
+		assert_eq!(core::str::from_utf8(&bytes), Ok("abé"));
+
+		let mut chars = crate::array::Array::<2, char>::new();
+		assert!(chars.write_str("nya").is_err());
+		assert!(chars.is_empty());
+	}
 
-impl<const N: usize, T: PartialOrd> PartialOrd for Array<N, T> {
-	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-		PartialOrd::partial_cmp(self.as_slice(), other.as_slice())
+	#[test]
+	fn test_map_panic() {
+		let drops = crate::test::Counter::new();
+		let mapped = crate::test::Counter::new();
+
+		let array = crate::array::Array::<8, _>::from_parts([0, 1, 2, 3, 4].map(|x| drops.counted(x)));
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.map(|x| {
+				if x.0 == 2 {
+					panic!("nya");
+				}
+				mapped.counted(x.0)
+			})
+		}));
+
+		assert!(result.is_err());
+		// every input is dropped once, either by `f` or by the iterator
+		assert_eq!(drops.drops(), 5);
+		// the two results so far are dropped with `out`
+		assert_eq!(mapped.drops(), 2);
 	}
-}
 
-impl<const N: usize, T: Eq> Eq for Array<N, T> {}
+	#[test]
+	fn test_extract_if() {
+		let drops = crate::test::Counter::new();
 
-impl<const N: usize, T: Ord> Ord for Array<N, T> {
-	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-		Ord::cmp(self.as_slice(), other.as_slice())
+		let mut array = crate::array::Array::<8, _>::from_parts([0, 1, 2, 3, 4, 5, 6].map(|x| drops.counted(x)));
+
+		let mut iter = array.extract_if(|x| x.0 % 3 == 0);
+		let first = iter.next().unwrap();
+		assert_eq!(first.0, 0);
+		drop(first);
+		assert_eq!(drops.drops(), 1);
+
+		// the rest of the matches, 3 and 6, are dropped with the iterator
+		drop(iter);
+		assert_eq!(drops.drops(), 3);
+		assert_eq!(array.map(|x| x.0), [1, 2, 4, 5]);
+		assert_eq!(drops.drops(), 7);
 	}
-}
 
-impl<const N: usize, const M: usize, T: PartialEq> PartialEq<Array<M, T>> for Array<N, T> {
-	fn eq(&self, other: &Array<M, T>) -> bool {
-		PartialEq::eq(self.as_slice(), other.as_slice())
+	#[test]
+	fn test_extract_if_panic() {
+		let mut array = array![1, 2, 3, 4, 5, 6 => 8];
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			let mut iter = array.extract_if(|x| {
+				if *x == 4 {
+					panic!("nya");
+				}
+				*x % 2 == 0
+			});
+			assert_eq!(iter.next(), Some(2));
+			iter.next();
+		}));
+
+		assert!(result.is_err());
+		// 2 was removed, and everything from 4 on was never decided
+		assert_eq!(array, [1, 3, 4, 5, 6]);
 	}
-}
 
-impl<const N: usize, T: PartialEq> PartialEq<&[T]> for Array<N, T> {
-	fn eq(&self, other: &&[T]) -> bool {
-		PartialEq::eq(self.as_slice(), *other)
+	#[test]
+	fn test_insert_slice() {
+		let mut array = array![1, 2, 3 => 8];
+
+		array.insert_slice(0, &[4, 5]);
+		assert_eq!(array, [4, 5, 1, 2, 3]);
+
+		array.insert_slice(2, &[6]);
+		assert_eq!(array, [4, 5, 6, 1, 2, 3]);
+
+		array.insert_slice(6, &[7, 8]);
+		assert_eq!(array, [4, 5, 6, 1, 2, 3, 7, 8]);
+
+		array.insert_slice(3, &[]);
+		assert_eq!(array.try_insert_slice(0, &[9]), Err(()));
+		assert_eq!(array, [4, 5, 6, 1, 2, 3, 7, 8]);
+
+		let mut array = array![1, 2 => 4];
+		assert_eq!(array.try_insert_slice(3, &[3]), Err(()));
+		assert_eq!(array.try_insert_slice(1, &[3, 4, 5]), Err(()));
+		assert_eq!(array, [1, 2]);
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.insert_slice(1, &[3, 4, 5]);
+		}));
+		assert!(result.is_err());
+		assert_eq!(array, [1, 2]);
 	}
-}
 
-impl<const N: usize, T: PartialEq> PartialEq<&mut [T]> for Array<N, T> {
-	fn eq(&self, other: &&mut [T]) -> bool {
-		PartialEq::eq(self.as_slice(), *other)
+	#[test]
+	fn test_insert_slice_panic() {
+		let drops = crate::test::Counter::panic_after_clones(2);
+
+		let src = [10, 11, 12, 13].map(|x| drops.counted(x));
+		let mut array = crate::array::Array::<8, _>::from_parts([0, 1, 2].map(|x| drops.counted(x)));
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.insert_slice(1, &src);
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(drops.drops(), 0);
+		// the two clones that succeeded stay, and the tail is moved back
+		assert_eq!(array.map(|x| x.0), [0, 10, 11, 1, 2]);
+		assert_eq!(drops.drops(), 5);
 	}
-}
 
-impl<const N: usize, const M: usize, T: PartialEq> PartialEq<[T; M]> for Array<N, T> {
-	fn eq(&self, other: &[T; M]) -> bool {
-		PartialEq::eq(self.as_slice(), other.as_slice())
+	#[test]
+	fn test_remove_range() {
+		let drops = crate::test::Counter::new();
+
+		let mut array = crate::array::Array::<8, _>::from_parts([0, 1, 2, 3, 4, 5].map(|x| drops.counted(x)));
+
+		array.remove_range(2..2);
+		assert_eq!(drops.drops(), 0);
+		assert_eq!(array.len(), 6);
+
+		array.remove_range(1..4);
+		assert_eq!(drops.drops(), 3);
+		assert!(array.iter().map(|x| x.0).eq([0, 4, 5]));
+
+		array.remove_range(..);
+		assert_eq!(drops.drops(), 6);
+		assert!(array.is_empty());
 	}
-}
 
-impl<const N: usize, const M: usize, T: PartialEq> PartialEq<&[T; M]> for Array<N, T> {
-	fn eq(&self, other: &&[T; M]) -> bool {
-		PartialEq::eq(self.as_slice(), other.as_slice())
+	#[test]
+	fn test_remove_range_panic() {
+		let drops = crate::test::Counter::new();
+
+		let mut array = crate::array::Array::<8, _>::from_parts([0, 1, 2, 3, 4, 5].map(|x| {
+			if x == 2 { drops.bomb(x) } else { drops.counted(x) }
+		}));
+
+		let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+			array.remove_range(1..4);
+		}));
+
+		assert!(result.is_err());
+		assert_eq!(drops.drops(), 3);
+		assert_eq!(array.map(|x| x.0), [0, 4, 5]);
 	}
-}
 
-impl<const N: usize, T: core::fmt::Debug> core::fmt::Debug for Array<N, T> {
-	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		core::fmt::Debug::fmt(self.as_slice(), f)
+	#[test]
+	fn test_fill_spare_from_slice() {
+		let mut array = array![1u8, 2 => 8];
+
+		assert_eq!(array.fill_spare_from_slice(b"abc"), 3);
+		assert_eq!(array, [1, 2, b'a', b'b', b'c']);
+
+		assert_eq!(array.fill_spare_from_slice(&[]), 0);
+		assert_eq!(array.len(), 5);
+
+		assert_eq!(array.fill_spare_from_slice(b"nyaaa"), 3);
+		assert_eq!(array, [1, 2, b'a', b'b', b'c', b'n', b'y', b'a']);
+
+		assert_eq!(array.fill_spare_from_slice(b"~"), 0);
+		assert_eq!(array.len(), 8);
 	}
-}
 
+	#[test]
+	fn test_macro_const_cap() {
+		const CAP: usize = 8;
 
-/// create an [`Array`].
-///
-/// like `vec!`, `array!` has similar syntax as Rust array expressions, with
-/// the addition of allowing one to specify the capacity of the `Array`
-/// by appending an `=>`:
-///
-/// ```
-/// # use nyarray::array;
-/// let array = array![1, 2, 3 => 6]; // capacity of 6 elements
-/// assert_eq!(array[0], 1);
-/// assert_eq!(array[1], 2);
-/// assert_eq!(array[2], 3);
-/// ```
-#[macro_export]
-macro_rules! array {
-	() => {
-		$crate::array::Array::new()
-	};
-	(=> $cap:literal) => {
-		$crate::array::Array::<$cap, _>::new()
-	};
-	($elem:expr; $n:expr) => {
-		$crate::array::from_elem($elem, $n)
-	};
-	($elem:expr; $n:expr => $cap:literal) => {
-		$crate::array::from_elem::<$cap, _>($elem, $n)
-	};
-	($($x:expr),+ $(,)?) => {
-		$crate::array::Array::from_parts([$($x),+])
-	};
-	($($x:expr),+ $(,)? => $cap:literal) => {
-		$crate::array::Array::<$cap, _>::from_parts([$($x),+])
-	};
-}
+		let array: crate::array::Array<CAP, i32> = array![=> CAP];
+		assert!(array.is_empty());
 
+		let array = array![1, 2, 3 => CAP];
+		assert_eq!(array.capacity(), CAP);
+		assert_eq!(array, [1, 2, 3]);
 
-#[cfg(test)]
-mod test {
-	extern crate std;
+		let array = array![7; 2 => core::mem::size_of::<u64>()];
+		assert_eq!(array.capacity(), 8);
+		assert_eq!(array, [7, 7]);
+
+		// literals still work
+		let array = array![1, 2 => 4];
+		assert_eq!(array.capacity(), 4);
+	}
 
 	#[test]
-	fn test_drop() {
-		static mut NUM: u32 = 0;
+	fn test_try_from_fn() {
+		let drops = crate::test::Counter::new();
 
-		struct Box<T> {
-			_inner: std::boxed::Box<T>,
-		}
-		impl<T> Box<T> {
-			fn new(inner: T) -> Self {
-				Self {
-					_inner: std::boxed::Box::new(inner),
-				}
-			}
-		}
-		impl<T> Drop for Box<T> {
-			fn drop(&mut self) {
-				unsafe {
-					NUM += 1;
-				}
-			}
-		}
+		let array = crate::array::Array::<8, _>::try_from_fn(5, |i| Ok::<_, ()>(drops.counted(i as i32))).unwrap();
+		assert_eq!(array.map(|x| x.0), [0, 1, 2, 3, 4]);
+		assert_eq!(drops.drops(), 5);
 
-		let array = array![Box::new(1), Box::new(2), Box::new(3) => 4];
+		let array = crate::array::Array::<8, _>::try_from_fn(5, |i| if i < 3 { Ok(drops.counted(i as i32)) } else { Err(i) });
+		assert!(matches!(array, Err(3)));
+		// the three built before the error are dropped
+		assert_eq!(drops.drops(), 8);
 
-		drop(array);
+		let array = crate::array::Array::<8, crate::test::Counted>::try_from_fn(0, |_| Err(()));
+		assert!(matches!(array, Ok(ref array) if array.is_empty()));
+	}
 
-		assert_eq!(unsafe { NUM }, 3);
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_eq_vec() {
+		assert_eq!(array![1, 2, 3 => 4], std::vec![1, 2, 3]);
+		assert_eq!(std::vec![1, 2, 3], array![1, 2, 3 => 4]);
+		assert_ne!(array![1, 2, 3 => 4], std::vec![1, 2]);
+		assert_ne!(std::vec::Vec::<i32>::new(), array![1 => 4]);
+		assert_eq!(std::vec::Vec::<i32>::new(), crate::array::Array::<4, i32>::new());
+	}
 
-		let array = array![Box::new(1), Box::new(2), Box::new(3) => 4];
-		let mut iter = array.into_iter();
-		iter.next();
+	#[test]
+	fn test_remove_first_last() {
+		let drops = crate::test::Counter::new();
 
-		drop(iter);
+		let mut array = crate::array::Array::<4, _>::from_parts([0, 1, 2, 3].map(|x| drops.counted(x)));
 
-		assert_eq!(unsafe { NUM }, 6);
+		let first = array.remove_first().unwrap();
+		assert_eq!(first.0, 0);
+		let last = array.remove_last().unwrap();
+		assert_eq!(last.0, 3);
+		// nothing is dropped while the elements are moved out
+		assert_eq!(drops.drops(), 0);
+		drop((first, last));
+		assert_eq!(drops.drops(), 2);
+
+		assert!(array.iter().map(|x| x.0).eq([1, 2]));
+
+		drop(array.remove_first());
+		drop(array.remove_first());
+		assert!(array.remove_first().is_none());
+		assert!(array.remove_last().is_none());
+		assert_eq!(drops.drops(), 4);
+
+		drop(array);
+		assert_eq!(drops.drops(), 4);
 	}
 
 	#[test]
-	fn test_iter() {
-		let array = array![std::boxed::Box::new(1) => 4];
-		let _ = array.iter().cloned().collect::<crate::array::Array<4, _>>();
+	fn test_repeat_empty() {
+		let array = crate::array::Array::<8, i32>::new();
+		assert_eq!(array.repeat_checked(usize::MAX), Some(crate::array::Array::new()));
+		assert!(array.repeat(usize::MAX).is_empty());
+
+		assert_eq!(array![1, 2 => 8].repeat_checked(0), Some(crate::array::Array::new()));
 	}
 }
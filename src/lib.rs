@@ -7,3 +7,80 @@ extern crate std;
 
 pub mod array;
 pub mod switch;
+
+#[cfg(test)]
+mod test {
+	extern crate std;
+
+	#[derive(Default)]
+	struct State {
+		drops: core::cell::Cell<u32>,
+		clones: core::cell::Cell<u32>,
+		clone_limit: core::cell::Cell<Option<u32>>,
+	}
+
+	/// counts the drops and clones of the [`Counted`] values it makes. every
+	/// test makes its own, so tests running in parallel don't share counts.
+	#[derive(Clone, Default)]
+	pub(crate) struct Counter(std::rc::Rc<State>);
+
+	impl Counter {
+		pub(crate) fn new() -> Self {
+			Self::default()
+		}
+
+		/// a counter whose values panic when cloned, once `limit` clones
+		/// have been made.
+		pub(crate) fn panic_after_clones(limit: u32) -> Self {
+			let counter = Self::new();
+			counter.0.clone_limit.set(Some(limit));
+			counter
+		}
+
+		pub(crate) fn drops(&self) -> u32 {
+			self.0.drops.get()
+		}
+
+		pub(crate) fn clones(&self) -> u32 {
+			self.0.clones.get()
+		}
+
+		pub(crate) fn counted(&self, value: i32) -> Counted {
+			Counted(value, self.clone(), false)
+		}
+
+		/// a value that panics after being counted, when dropped.
+		pub(crate) fn bomb(&self, value: i32) -> Counted {
+			Counted(value, self.clone(), true)
+		}
+	}
+
+	/// a value that reports its drops and clones to a [`Counter`].
+	pub(crate) struct Counted(pub(crate) i32, Counter, bool);
+
+	impl Clone for Counted {
+		fn clone(&self) -> Self {
+			let state = &self.1.0;
+			if state.clone_limit.get() == Some(state.clones.get()) {
+				panic!("clone limit reached");
+			}
+			state.clones.set(state.clones.get() + 1);
+			Counted(self.0, self.1.clone(), self.2)
+		}
+
+		/// reuses `self` without cloning, so it isn't counted.
+		fn clone_from(&mut self, source: &Self) {
+			self.0 = source.0;
+		}
+	}
+
+	impl Drop for Counted {
+		fn drop(&mut self) {
+			let state = &self.1.0;
+			state.drops.set(state.drops.get() + 1);
+			if self.2 {
+				panic!("boom");
+			}
+		}
+	}
+}
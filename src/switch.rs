@@ -64,6 +64,27 @@ enum Inner<const N: usize, T> {
 /// see the [module level documentation](self).
 pub struct SwitchVec<const N: usize, T> {
 	inner: Inner<N, T>,
+	policy: SpillPolicy,
+}
+
+/// when a stack-allocated [`SwitchVec`] should move its elements onto the heap.
+///
+/// in `no_std`, there is no heap to move to, so this has no effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpillPolicy {
+	/// move onto the heap once the stack capacity is exceeded. this is the default.
+	#[default]
+	Lazy,
+	/// move onto the heap as soon as anything needs to be reserved, ie; on the
+	/// first push. useful if the vector is known to end up large anyways.
+	Eager,
+	/// move onto the heap once there would be more than this many elements,
+	/// even though the stack capacity isn't exceeded yet. values greater than
+	/// the stack capacity behave like [`SpillPolicy::Lazy`].
+	///
+	/// useful for spilling early without changing the vector's type, eg; when
+	/// a `SwitchVec` type is shared by callers with different needs.
+	At(usize),
 }
 
 impl<const N: usize, T> SwitchVec<N, T> {
@@ -79,8 +100,96 @@ impl<const N: usize, T> SwitchVec<N, T> {
 	#[inline]
 	pub const fn new() -> Self {
 		Self {
-			inner: Inner::Stack(crate::array::Array::new())
+			inner: Inner::Stack(crate::array::Array::new()),
+			policy: SpillPolicy::Lazy,
+		}
+	}
+
+	/// construct a new, stack-allocated [`SwitchVec`] that moves onto the heap
+	/// according to `policy`. [`Self::new()`] uses [`SpillPolicy::Lazy`].
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::{SwitchVec, SpillPolicy};
+	/// let mut vec = SwitchVec::<4, _>::new_with_spill_policy(SpillPolicy::Eager);
+	///
+	/// assert!(!vec.is_heap());
+	///
+	/// vec.push(1).unwrap();
+	///
+	/// assert!(vec.is_heap());
+	/// ```
+	#[inline]
+	pub const fn new_with_spill_policy(policy: SpillPolicy) -> Self {
+		Self {
+			inner: Inner::Stack(crate::array::Array::new()),
+			policy,
+		}
+	}
+
+	/// construct a new [`SwitchVec`] with space for at least `capacity` elements.
+	///
+	/// if `capacity` fits in the array capacity `N`, the vector starts on the
+	/// stack. otherwise, it starts on the heap with a `Vec` of that capacity.
+	/// in `no_std`, the vector always starts on the stack, and its capacity is
+	/// `N` regardless.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// let vec = SwitchVec::<4, i32>::with_capacity(2);
+	/// assert!(!vec.is_heap());
+	/// assert_eq!(vec.capacity(), 4);
+	///
+	/// let vec = SwitchVec::<4, i32>::with_capacity(16);
+	/// assert!(vec.is_heap());
+	/// assert!(vec.capacity() >= 16);
+	/// ```
+	#[inline]
+	pub fn with_capacity(capacity: usize) -> Self {
+		#[cfg(feature = "std")]
+		if capacity > N {
+			return Self::from_vec(std::vec::Vec::with_capacity(capacity));
 		}
+
+		#[cfg(not(feature = "std"))]
+		let _ = capacity;
+
+		Self::new()
+	}
+
+	/// returns the [`SpillPolicy`] of this vector.
+	#[inline]
+	pub const fn spill_policy(&self) -> SpillPolicy {
+		self.policy
+	}
+
+	/// change the [`SpillPolicy`] of this vector.
+	///
+	/// the policy only decides when [`Self::reserve()`], and so methods like
+	/// [`Self::push()`], move the vector onto the heap. it doesn't move the
+	/// vector by itself, and [`Self::switch_heap()`] and [`Self::switch_stack()`]
+	/// ignore it.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::{SwitchVec, SpillPolicy};
+	/// let mut vec = SwitchVec::<16, _>::new();
+	/// vec.set_spill_policy(SpillPolicy::At(2));
+	///
+	/// vec.push(1).unwrap();
+	/// vec.push(2).unwrap();
+	/// assert!(!vec.is_heap());
+	///
+	/// vec.push(3).unwrap();
+	/// assert!(vec.is_heap());
+	/// ```
+	#[inline]
+	pub const fn set_spill_policy(&mut self, policy: SpillPolicy) {
+		self.policy = policy;
 	}
 
 	/// construct a [`SwitchVec`] from a `Vec`.
@@ -98,7 +207,8 @@ impl<const N: usize, T> SwitchVec<N, T> {
 	#[inline]
 	pub const fn from_vec(vec: std::vec::Vec<T>) -> Self {
 		Self {
-			inner: Inner::Heap(vec)
+			inner: Inner::Heap(vec),
+			policy: SpillPolicy::Lazy,
 		}
 	}
 
@@ -134,7 +244,8 @@ impl<const N: usize, T> SwitchVec<N, T> {
 	#[inline]
 	pub const fn from_array(array: crate::array::Array<N, T>) -> Self {
 		Self {
-			inner: Inner::Stack(array)
+			inner: Inner::Stack(array),
+			policy: SpillPolicy::Lazy,
 		}
 	}
 
@@ -318,7 +429,9 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		}
 	}
 
-	/// removes all elements from the vector.
+	/// removes all elements from the vector. if the vector is on the heap,
+	/// it stays there, and keeps its allocation for reuse.
+	/// see [`Self::clear_to_stack()`] to free it instead.
 	///
 	/// ## examples
 	///
@@ -338,6 +451,116 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		}
 	}
 
+	/// removes all elements from the vector, and frees any heap allocation,
+	/// returning the vector to the stack.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let mut vec = SwitchVec::from_array(array![1, 2, 3 => 4]);
+	/// vec.switch_heap();
+	///
+	/// vec.clear_to_stack();
+	/// assert!(vec.is_empty());
+	/// assert!(!vec.is_heap());
+	/// ```
+	#[inline]
+	pub fn clear_to_stack(&mut self) {
+		match &mut self.inner {
+			Inner::Stack(array) => array.clear(),
+			#[cfg(feature = "std")]
+			Inner::Heap(_) => self.inner = Inner::Stack(crate::array::Array::new()),
+		}
+	}
+
+	/// shortens the vector to `len` elements, dropping the rest.
+	/// if `len` is greater or equal to [`Self::len()`], this does nothing.
+	///
+	/// this never moves the vector between the stack and the heap.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let mut vec = SwitchVec::from_array(array![1, 2, 3, 4, 5 => 8]);
+	///
+	/// vec.truncate(2);
+	/// assert_eq!(vec, [1, 2]);
+	///
+	/// vec.truncate(4);
+	/// assert_eq!(vec, [1, 2]);
+	/// ```
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// let mut vec = SwitchVec::<2, _>::from_vec(vec![1, 2, 3, 4, 5]);
+	///
+	/// vec.truncate(1);
+	/// assert_eq!(vec, [1]);
+	/// assert!(vec.is_heap());
+	/// ```
+	#[inline]
+	pub fn truncate(&mut self, len: usize) {
+		match &mut self.inner {
+			Inner::Stack(array) => array.truncate(len),
+			#[cfg(feature = "std")]
+			Inner::Heap(vec) => vec.truncate(len),
+		}
+	}
+
+	/// keep only the elements for which `f` returns `true`, in order.
+	///
+	/// this never moves the vector between the stack and the heap.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let mut vec = SwitchVec::from_array(array![1, 2, 3, 4, 5 => 8]);
+	///
+	/// vec.retain(|x| x % 2 == 1);
+	/// assert_eq!(vec, [1, 3, 5]);
+	/// ```
+	#[inline]
+	pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+		match &mut self.inner {
+			Inner::Stack(array) => array.retain(f),
+			#[cfg(feature = "std")]
+			Inner::Heap(vec) => vec.retain(f),
+		}
+	}
+
+	/// keep only the elements for which `f` returns `true`, in order.
+	/// `f` may also modify the elements.
+	///
+	/// this never moves the vector between the stack and the heap.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let mut vec = SwitchVec::from_array(array![1, 2, 3, 4, 5 => 8]);
+	///
+	/// vec.retain_mut(|x| {
+	///     *x *= 10;
+	///     *x != 30
+	/// });
+	/// assert_eq!(vec, [10, 20, 40, 50]);
+	/// ```
+	#[inline]
+	pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, f: F) {
+		match &mut self.inner {
+			Inner::Stack(array) => array.retain_mut(f),
+			#[cfg(feature = "std")]
+			Inner::Heap(vec) => vec.retain_mut(f),
+		}
+	}
+
 	/// move this vector's elements onto the heap, if not already done so.
 	/// returns `true` if successful.
 	/// returns `false` if the operation failed for whatever reason.
@@ -407,7 +630,41 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		}
 	}
 
-	/// move this vector's elements onto the heap, if not already done so.
+	/// move this vector's elements onto the stack, if not already done so.
+	/// returns `true` if successful.
+	/// returns `false` if there are more elements than fit in the array, in
+	/// which case nothing happens. see [`Self::switch_stack_truncate()`] to
+	/// discard the extra elements instead.
+	///
+	/// in `no_std`, this is a no-op, and always returns `true`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use std::vec;
+	/// let mut vec = SwitchVec::<4, _>::from_vec(vec![1, 2, 3, 4, 5]);
+	///
+	/// assert!(!vec.switch_stack());
+	/// assert!(vec.is_heap());
+	///
+	/// vec.pop();
+	///
+	/// assert!(vec.switch_stack());
+	/// assert!(!vec.is_heap());
+	///
+	/// assert_eq!(vec, [1, 2, 3, 4]);
+	/// ```
+	#[must_use]
+	pub fn switch_stack(&mut self) -> bool {
+		if self.len() > N {
+			return false;
+		}
+
+		self.switch_stack_truncate()
+	}
+
+	/// move this vector's elements onto the stack, if not already done so.
 	/// this is a lossy operation - elements that don't fit in the array
 	/// will be discarded.
 	/// returns `true` if successful.
@@ -424,14 +681,14 @@ impl<const N: usize, T> SwitchVec<N, T> {
 	///
 	/// assert!(vec.is_heap());
 	///
-	/// vec.switch_stack();
+	/// vec.switch_stack_truncate();
 	///
 	/// assert!(!vec.is_heap());
 	///
 	/// assert_eq!(vec, [1, 2, 3, 4]);
 	/// ```
 	#[must_use]
-	pub fn switch_stack(&mut self) -> bool {
+	pub fn switch_stack_truncate(&mut self) -> bool {
 		#[cfg(feature = "std")]
 		{
 			let vec = match &mut self.inner {
@@ -472,6 +729,40 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		}
 	}
 
+	/// reduce the memory used by the vector. if it's on the heap, and its
+	/// elements fit in the array capacity, they're moved back onto the stack.
+	/// otherwise, the heap allocation is shrunk to fit.
+	///
+	/// in `no_std`, this is a no-op.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use std::vec;
+	/// let mut vec = SwitchVec::<4, _>::from_vec(vec![1, 2, 3, 4, 5]);
+	///
+	/// vec.shrink_to_fit();
+	/// assert!(vec.is_heap());
+	///
+	/// vec.pop();
+	/// vec.shrink_to_fit();
+	/// assert!(!vec.is_heap());
+	/// assert_eq!(vec, [1, 2, 3, 4]);
+	/// ```
+	pub fn shrink_to_fit(&mut self) {
+		#[cfg(feature = "std")]
+		if let Inner::Heap(vec) = &mut self.inner {
+			if vec.len() <= N {
+				// the elements fit, so nothing is lost
+				let switched = self.switch_stack();
+				debug_assert!(switched);
+			} else {
+				vec.shrink_to_fit();
+			}
+		}
+	}
+
 	/// ensure [`Self::capacity()`] has enough space for `additional` number of element.
 	/// returns `true` if there is enough space, or if not, memory was successfully allocated.
 	/// returns `false` if memory could not be allocated for whatever reason.
@@ -505,7 +796,15 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		{
 			match &mut self.inner {
 				Inner::Stack(array) => {
-					if array.len() + additional <= array.capacity() {
+					let fits = match self.policy {
+						SpillPolicy::Lazy => array.len() + additional <= array.capacity(),
+						SpillPolicy::Eager => additional == 0,
+						SpillPolicy::At(threshold) => {
+							array.len() + additional <= threshold.min(array.capacity())
+						}
+					};
+
+					if fits {
 						return true;
 					}
 
@@ -534,6 +833,36 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		}
 	}
 
+	/// like [`Self::reserve()`], but reports whether this call moved the
+	/// vector's elements from the stack to the heap. any pointers into the
+	/// vector are invalidated when that happens.
+	///
+	/// returns `Ok(true)` if the vector spilled onto the heap, `Ok(false)` if
+	/// it already had enough space or was already on the heap, and `Err(())`
+	/// if memory could not be allocated.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let mut vec = SwitchVec::<4, _>::from_array(array![1, 2, 3]);
+	///
+	/// assert_eq!(vec.reserve_reporting(1), Ok(false));
+	/// assert_eq!(vec.reserve_reporting(2), Ok(true));
+	/// assert_eq!(vec.reserve_reporting(8), Ok(false));
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn reserve_reporting(&mut self, additional: usize) -> Result<bool, ()> {
+		let heap = self.is_heap();
+
+		if !self.reserve(additional) {
+			return Err(());
+		}
+
+		Ok(heap != self.is_heap())
+	}
+
 	/// add an element to the end of the vector, returning
 	/// `Err(T)` if the operation failed.
 	///
@@ -586,9 +915,47 @@ impl<const N: usize, T> SwitchVec<N, T> {
 		}
 	}
 
+	/// clone every element of `other` onto the end of the vector, moving onto
+	/// the heap first if they don't fit. returns `Err(())` if there wasn't
+	/// enough space, in which case the vector is left untouched.
+	///
+	/// unlike [`Extend`], the space is reserved once up front.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let mut vec = SwitchVec::from_array(array![1, 2 => 4]);
+	///
+	/// vec.extend_from_slice(&[3, 4, 5]).unwrap();
+	/// assert_eq!(vec, [1, 2, 3, 4, 5]);
+	/// assert!(vec.is_heap());
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn extend_from_slice(&mut self, other: &[T]) -> Result<(), ()>
+	where
+		T: Clone,
+	{
+		if !self.reserve(other.len()) {
+			return Err(());
+		}
+
+		match &mut self.inner {
+			Inner::Stack(array) => array.extend_from_slice(other),
+			#[cfg(feature = "std")]
+			Inner::Heap(vec) => vec.extend_from_slice(other),
+		}
+
+		Ok(())
+	}
+
 	/// insert an element into any index of the vector, shifting
 	/// all elements after towards the end.
 	///
+	/// returns `Err(T)` if `index` is greater than [`Self::len()`], or if there
+	/// wasn't enough space.
+	///
 	/// ## examples
 	///
 	/// ```
@@ -604,9 +971,15 @@ impl<const N: usize, T> SwitchVec<N, T> {
 	///
 	/// vec.insert(5, 30).unwrap();
 	/// assert_eq!(vec, [20, 1, 2, 10, 3, 30]);
+	///
+	/// assert_eq!(vec.insert(7, 40), Err(40));
 	/// ```
 	#[inline]
 	pub fn insert(&mut self, index: usize, element: T) -> Result<(), T> {
+		if index > self.len() {
+			return Err(element);
+		}
+
 		if !self.reserve(1) {
 			return Err(element);
 		}
@@ -687,6 +1060,161 @@ impl<const N: usize, T> SwitchVec<N, T> {
 			Inner::Heap(vec) => Some(vec.swap_remove(index)),
 		}
 	}
+
+	/// returns an iterator over the elements and their indices.
+	/// this is the same as `vec.iter().enumerate()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let vec = SwitchVec::from_array(array!['a', 'b', 'c' => 3]);
+	///
+	/// assert!(vec.enumerate().eq([(0, &'a'), (1, &'b'), (2, &'c')]));
+	/// ```
+	#[inline]
+	pub fn enumerate(&self) -> impl Iterator<Item = (usize, &T)> {
+		self.as_slice().iter().enumerate()
+	}
+
+	/// returns an iterator over copies of the elements.
+	/// this is the same as `vec.iter().copied()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let vec = SwitchVec::from_array(array![1, 2, 3 => 4]);
+	///
+	/// assert_eq!(vec.copied().sum::<i32>(), 6);
+	/// ```
+	#[inline]
+	pub fn copied(&self) -> impl Iterator<Item = T> + '_
+	where
+		T: Copy,
+	{
+		self.as_slice().iter().copied()
+	}
+
+	/// returns an iterator over clones of the elements.
+	/// this is the same as `vec.iter().cloned()`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let vec = SwitchVec::from_array(array![1, 2, 3 => 4]);
+	///
+	/// assert_eq!(vec.cloned().max(), Some(3));
+	/// ```
+	#[inline]
+	pub fn cloned(&self) -> impl Iterator<Item = T> + '_
+	where
+		T: Clone,
+	{
+		self.as_slice().iter().cloned()
+	}
+
+	/// call `f` on every element in order, stopping at the first error.
+	/// this is the same as `vec.iter().try_for_each(f)`.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let vec = SwitchVec::from_array(array![1, -2, 3, -4 => 4]);
+	///
+	/// let positive = vec.try_for_each(|x| if *x > 0 { Ok(()) } else { Err(*x) });
+	/// assert_eq!(positive, Err(-2));
+	/// ```
+	#[inline]
+	pub fn try_for_each<E, F: FnMut(&T) -> Result<(), E>>(&self, f: F) -> Result<(), E> {
+		self.as_slice().iter().try_for_each(f)
+	}
+
+	/// consume the vector into an iterator over owned chunks of `C` elements.
+	/// the last chunk has less than `C` elements if the length isn't a
+	/// multiple of `C`.
+	///
+	/// elements that haven't been yielded yet are dropped along with the iterator.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// # use nyarray::array;
+	/// let vec = SwitchVec::from_array(array![1, 2, 3, 4, 5 => 8]);
+	///
+	/// let mut chunks = vec.into_chunks::<2>();
+	/// assert_eq!(chunks.next(), Some(array![1, 2]));
+	/// assert_eq!(chunks.next(), Some(array![3, 4]));
+	/// assert_eq!(chunks.next(), Some(array![5 => 2]));
+	/// assert_eq!(chunks.next(), None);
+	/// ```
+	///
+	/// ## panics
+	///
+	/// this method panics if `C` is `0`.
+	pub fn into_chunks<const C: usize>(self) -> impl Iterator<Item = crate::array::Array<C, T>> {
+		if C == 0 {
+			panic!("chunk size must be non-zero");
+		}
+
+		let mut iter = self.into_iter();
+
+		core::iter::from_fn(move || {
+			let mut chunk = crate::array::Array::new();
+
+			for element in iter.by_ref().take(C) {
+				unsafe {
+					// safety: at most `C` elements are taken
+					chunk.push_unchecked(element);
+				}
+			}
+
+			if chunk.is_empty() {
+				None
+			} else {
+				Some(chunk)
+			}
+		})
+	}
+}
+
+impl<const N: usize> SwitchVec<N, char> {
+	/// push every `char` of `s` onto the end of the vector. returns `Err(())`
+	/// if they don't all fit, in which case the vector is left untouched.
+	///
+	/// ## examples
+	///
+	/// ```
+	/// # use nyarray::switch::SwitchVec;
+	/// let mut vec = SwitchVec::<4, char>::new();
+	///
+	/// vec.push_str("nya").unwrap();
+	/// vec.push_str("nya").unwrap();
+	/// assert_eq!(vec, ['n', 'y', 'a', 'n', 'y', 'a']);
+	/// ```
+	#[expect(clippy::result_unit_err, reason = "the only failure is running out of capacity")]
+	pub fn push_str(&mut self, s: &str) -> Result<(), ()> {
+		if !self.reserve(s.chars().count()) {
+			return Err(());
+		}
+
+		for c in s.chars() {
+			match &mut self.inner {
+				Inner::Stack(array) => array.push(c),
+				#[cfg(feature = "std")]
+				Inner::Heap(vec) => vec.push(c),
+			}
+		}
+
+		Ok(())
+	}
 }
 
 impl<const N: usize, T> Default for SwitchVec<N, T> {
@@ -697,7 +1225,9 @@ impl<const N: usize, T> Default for SwitchVec<N, T> {
 
 impl<const N: usize, T: Clone> Clone for SwitchVec<N, T> {
 	fn clone(&self) -> Self {
-		self.iter().cloned().collect()
+		let mut out = Self::new_with_spill_policy(self.policy);
+		out.extend(self.iter().cloned());
+		out
 	}
 }
 
@@ -793,6 +1323,14 @@ impl<const N: usize, T> Iterator for IntoIter<N, T> {
 			IntoIterInner::Heap(vec) => vec.next(),
 		}
 	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match &self.inner {
+			IntoIterInner::Stack(array) => array.size_hint(),
+			#[cfg(feature = "std")]
+			IntoIterInner::Heap(vec) => vec.size_hint(),
+		}
+	}
 }
 
 impl<const N: usize, T> DoubleEndedIterator for IntoIter<N, T> {
@@ -805,10 +1343,18 @@ impl<const N: usize, T> DoubleEndedIterator for IntoIter<N, T> {
 	}
 }
 
+impl<const N: usize, T> ExactSizeIterator for IntoIter<N, T> {}
+
+impl<const N: usize, T> core::iter::FusedIterator for IntoIter<N, T> {}
+
 impl<const N: usize, T> IntoIterator for SwitchVec<N, T> {
 	type IntoIter = IntoIter<N, T>;
 	type Item = T;
 
+	/// consume the vector into an iterator over its elements.
+	///
+	/// if the vector is heap-allocated, this reuses the underlying `Vec`'s
+	/// allocation as-is; no elements are copied and nothing is reallocated.
 	fn into_iter(self) -> Self::IntoIter {
 		IntoIter {
 			inner: match self.inner {
@@ -838,12 +1384,29 @@ impl<'a, const N: usize, T> IntoIterator for &'a mut SwitchVec<N, T> {
 	}
 }
 
+/// collects elements into a vec.
+///
+/// with `std`, this never runs out of space. without `std`, elements that
+/// don't fit are discarded by default, or panic with the `strict-collect`
+/// feature enabled.
 impl<const N: usize, T> FromIterator<T> for SwitchVec<N, T> {
+	#[cfg(not(feature = "strict-collect"))]
 	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
 		let mut out = Self::new();
 		out.extend(iter);
 		out
 	}
+
+	#[cfg(feature = "strict-collect")]
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut out = Self::new();
+		for i in iter {
+			if out.push(i).is_err() {
+				panic!("collect exceeds capacity");
+			}
+		}
+		out
+	}
 }
 
 
@@ -891,8 +1454,577 @@ impl<const N: usize, const M: usize, T: PartialEq> PartialEq<&[T; M]> for Switch
 	}
 }
 
+#[cfg(feature = "std")]
+impl<const N: usize, T: PartialEq> PartialEq<std::vec::Vec<T>> for SwitchVec<N, T> {
+	fn eq(&self, other: &std::vec::Vec<T>) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize, T: PartialEq> PartialEq<SwitchVec<N, T>> for std::vec::Vec<T> {
+	fn eq(&self, other: &SwitchVec<N, T>) -> bool {
+		PartialEq::eq(self.as_slice(), other.as_slice())
+	}
+}
+
+/// `{:?}` formats the elements as a slice. the alternate form, `{:#?}`,
+/// also shows the backing storage and capacity.
 impl<const N: usize, T: core::fmt::Debug> core::fmt::Debug for SwitchVec<N, T> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		core::fmt::Debug::fmt(self.as_slice(), f)
+		if !f.alternate() {
+			return core::fmt::Debug::fmt(self.as_slice(), f);
+		}
+
+		let name = if self.is_heap() {
+			"SwitchVec::Heap"
+		} else {
+			"SwitchVec::Stack"
+		};
+
+		f.debug_struct(name)
+			.field("capacity", &self.capacity())
+			.field("elements", &self.as_slice())
+			.finish()
+	}
+}
+
+/// appends the bytes, moving onto the heap as needed. a write only fails if
+/// the heap allocation does, in which case nothing is written.
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::Write for SwitchVec<N, u8> {
+	fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+		self.extend_from_slice(buf)
+			.map(|()| buf.len())
+			.map_err(|()| std::io::ErrorKind::OutOfMemory.into())
+	}
+
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+/// reads bytes from the front of the vector, removing them. every read
+/// shifts the remaining bytes down, so this is best for small buffers.
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::Read for SwitchVec<N, u8> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let count = buf.len().min(self.len());
+		buf[..count].copy_from_slice(&self.as_slice()[..count]);
+		std::io::BufRead::consume(self, count);
+		Ok(count)
+	}
+}
+
+/// the buffer is every byte in the vector. consuming removes bytes from the
+/// front.
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::BufRead for SwitchVec<N, u8> {
+	fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+		Ok(self.as_slice())
+	}
+
+	fn consume(&mut self, amount: usize) {
+		let amount = amount.min(self.len());
+		match &mut self.inner {
+			Inner::Stack(array) => array.remove_range(..amount),
+			Inner::Heap(vec) => {
+				vec.drain(..amount);
+			}
+		}
+	}
+}
+
+/// serializes the elements as a sequence.
+#[cfg(feature = "serde")]
+impl<const N: usize, T: serde::Serialize> serde::Serialize for SwitchVec<N, T> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.as_slice())
+	}
+}
+
+/// deserializes a sequence onto the stack, switching to the heap once it
+/// runs out of space. in `no_std`, this fails if the sequence has more
+/// elements than the capacity.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, T: serde::Deserialize<'de>> serde::Deserialize<'de> for SwitchVec<N, T> {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		struct Visitor<const N: usize, T>(core::marker::PhantomData<T>);
+
+		impl<'de, const N: usize, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for Visitor<N, T> {
+			type Value = SwitchVec<N, T>;
+
+			fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+				if cfg!(feature = "std") {
+					write!(f, "a sequence")
+				} else {
+					write!(f, "a sequence of at most {N} elements")
+				}
+			}
+
+			fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut out = SwitchVec::new();
+
+				while let Some(element) = seq.next_element()? {
+					if out.push(element).is_err() {
+						return Err(serde::de::Error::invalid_length(out.len() + 1, &self));
+					}
+				}
+
+				Ok(out)
+			}
+		}
+
+		deserializer.deserialize_seq(Visitor(core::marker::PhantomData))
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	extern crate std;
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_into_iter_heap_no_realloc() {
+		let vec = std::vec![1, 2, 3, 4, 5];
+		let ptr = vec.as_ptr();
+
+		let switch = crate::switch::SwitchVec::<4, _>::from_vec(vec);
+		let iter = switch.into_iter();
+
+		let super::IntoIterInner::Heap(inner) = &iter.inner else {
+			panic!("heap-allocated vec produced a stack iterator");
+		};
+		assert_eq!(inner.as_slice().as_ptr(), ptr);
+
+		assert!(iter.eq([1, 2, 3, 4, 5]));
+	}
+
+	#[test]
+	fn test_into_iter_len() {
+		let mut vecs = both_backings::<i32>();
+
+		for vec in &mut vecs {
+			vec.extend([1, 2, 3, 4, 5]);
+
+			let mut iter = core::mem::take(vec).into_iter();
+			assert_eq!(iter.len(), 5);
+			assert_eq!(iter.size_hint(), (5, Some(5)));
+
+			iter.next();
+			iter.next_back();
+			assert_eq!(iter.len(), 3);
+
+			iter.by_ref().for_each(drop);
+			assert_eq!(iter.len(), 0);
+			assert_eq!(iter.next(), None);
+		}
+	}
+
+	/// an empty stack-backed vector, and when `std` is enabled, an empty
+	/// heap-backed one.
+	fn both_backings<T>() -> std::vec::Vec<crate::switch::SwitchVec<8, T>> {
+		std::vec![
+			crate::switch::SwitchVec::new(),
+			#[cfg(feature = "std")]
+			crate::switch::SwitchVec::from_vec(std::vec::Vec::new()),
+		]
+	}
+
+	/// run `f` on `vec`, asserting it didn't switch backing storage.
+	fn assert_mode_kept<const N: usize, T>(
+		vec: &mut crate::switch::SwitchVec<N, T>,
+		f: impl FnOnce(&mut crate::switch::SwitchVec<N, T>),
+	) {
+		let heap = vec.is_heap();
+		f(vec);
+		assert_eq!(vec.is_heap(), heap);
+	}
+
+	#[test]
+	fn test_mode_kept() {
+		let mut vecs = both_backings::<i32>();
+
+		for vec in &mut vecs {
+			vec.extend([1, 2, 3, 4, 5, 6]);
+
+			assert_mode_kept(vec, |v| { v.pop(); });
+			assert_mode_kept(vec, |v| { v.remove(0); });
+			assert_mode_kept(vec, |v| { v.swap_remove(0); });
+			assert_mode_kept(vec, |v| v.as_mut_slice().reverse());
+			assert_mode_kept(vec, |v| v.truncate(8));
+			assert_mode_kept(vec, |v| v.retain(|x| *x != 3));
+			assert_mode_kept(vec, |v| v.retain_mut(|_| true));
+			assert_mode_kept(vec, |v| v.truncate(2));
+			assert_mode_kept(vec, |v| v.clear());
+			assert_mode_kept(vec, |v| { v.pop(); });
+		}
+	}
+
+	#[test]
+	fn test_clear() {
+		let mut vec = crate::switch::SwitchVec::<4, i32>::new();
+		vec.extend([1, 2, 3]);
+
+		vec.clear_to_stack();
+		assert!(vec.is_empty());
+		assert!(!vec.is_heap());
+
+		#[cfg(feature = "std")]
+		{
+			vec.extend([1, 2, 3, 4, 5, 6]);
+			assert!(vec.is_heap());
+
+			vec.clear();
+			assert!(vec.is_empty());
+			assert!(vec.is_heap());
+			assert!(vec.capacity() >= 6);
+
+			vec.extend([1, 2, 3, 4, 5, 6]);
+			vec.clear_to_stack();
+			assert!(vec.is_empty());
+			assert!(!vec.is_heap());
+			assert_eq!(vec.capacity(), 4);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn test_serde() {
+		let vec: crate::switch::SwitchVec<4, i32> = serde_json::from_str("[1,2,3]").unwrap();
+		assert_eq!(vec, [1, 2, 3]);
+		assert!(!vec.is_heap());
+		assert_eq!(serde_json::to_string(&vec).unwrap(), "[1,2,3]");
+
+		let result = serde_json::from_str::<crate::switch::SwitchVec<4, i32>>("[1,2,3,4,5,6]");
+
+		#[cfg(feature = "std")]
+		{
+			let vec = result.unwrap();
+			assert_eq!(vec, [1, 2, 3, 4, 5, 6]);
+			assert!(vec.is_heap());
+			assert_eq!(serde_json::to_string(&vec).unwrap(), "[1,2,3,4,5,6]");
+		}
+
+		#[cfg(not(feature = "std"))]
+		assert!(result.is_err());
+	}
+
+	#[test]
+	fn test_reserve_reporting() {
+		let mut vec = crate::switch::SwitchVec::<4, i32>::new();
+		vec.extend([1, 2, 3]);
+
+		assert_eq!(vec.reserve_reporting(0), Ok(false));
+		assert_eq!(vec.reserve_reporting(1), Ok(false));
+		assert!(!vec.is_heap());
+
+		#[cfg(feature = "std")]
+		{
+			assert_eq!(vec.reserve_reporting(2), Ok(true));
+			assert!(vec.is_heap());
+			assert_eq!(vec.reserve_reporting(16), Ok(false));
+			assert!(vec.is_heap());
+		}
+
+		#[cfg(not(feature = "std"))]
+		assert_eq!(vec.reserve_reporting(2), Err(()));
+	}
+
+	#[test]
+	fn test_into_chunks() {
+		let mut vecs = both_backings();
+
+		for vec in &mut vecs {
+			let drops = crate::test::Counter::new();
+
+			vec.extend((0..7).map(|x| drops.counted(x)));
+
+			let mut chunks = core::mem::take(vec).into_chunks::<3>();
+
+			let first = chunks.next().unwrap();
+			assert!(first.iter().map(|x| x.0).eq(0..3));
+
+			let second = chunks.next().unwrap();
+			assert!(second.iter().map(|x| x.0).eq(3..6));
+
+			// the unyielded last chunk is dropped with the iterator
+			drop(chunks);
+			assert_eq!(drops.drops(), 1);
+
+			drop(first);
+			drop(second);
+			assert_eq!(drops.drops(), 7);
+		}
+
+		for vec in &mut vecs {
+			vec.extend((0..6).map(|x| crate::test::Counter::new().counted(x)));
+
+			let chunks = core::mem::take(vec).into_chunks::<3>();
+			assert_eq!(chunks.map(|chunk| chunk.len()).collect::<std::vec::Vec<_>>(), [3, 3]);
+		}
+	}
+
+	#[test]
+	fn test_retain() {
+		let mut vecs = both_backings::<i32>();
+
+		for vec in &mut vecs {
+			vec.extend([1, 2, 3, 4, 5, 6]);
+
+			vec.retain(|x| *x != 1);
+			assert_eq!(*vec, [2, 3, 4, 5, 6]);
+			vec.retain(|x| *x != 4);
+			assert_eq!(*vec, [2, 3, 5, 6]);
+			vec.retain(|x| *x != 6);
+			assert_eq!(*vec, [2, 3, 5]);
+
+			vec.retain_mut(|x| {
+				*x += 1;
+				*x != 3
+			});
+			assert_eq!(*vec, [4, 6]);
+
+			vec.retain(|_| false);
+			assert!(vec.is_empty());
+		}
+	}
+
+	#[test]
+	fn test_with_capacity() {
+		let vec = crate::switch::SwitchVec::<4, i32>::with_capacity(0);
+		assert!(!vec.is_heap());
+		assert_eq!(vec.capacity(), 4);
+
+		let vec = crate::switch::SwitchVec::<4, i32>::with_capacity(4);
+		assert!(!vec.is_heap());
+		assert_eq!(vec.capacity(), 4);
+
+		let vec = crate::switch::SwitchVec::<4, i32>::with_capacity(5);
+
+		#[cfg(feature = "std")]
+		{
+			assert!(vec.is_heap());
+			assert!(vec.capacity() >= 5);
+		}
+
+		#[cfg(not(feature = "std"))]
+		{
+			assert!(!vec.is_heap());
+			assert_eq!(vec.capacity(), 4);
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_shrink_to_fit() {
+		let mut vec = crate::switch::SwitchVec::<4, i32>::new();
+		vec.extend(0..32);
+		assert!(vec.is_heap());
+
+		vec.truncate(8);
+		vec.shrink_to_fit();
+		assert!(vec.is_heap());
+		assert!(vec.capacity() < 32);
+
+		vec.truncate(4);
+		vec.shrink_to_fit();
+		assert!(!vec.is_heap());
+		assert_eq!(vec, [0, 1, 2, 3]);
+
+		vec.shrink_to_fit();
+		assert!(!vec.is_heap());
+		assert_eq!(vec, [0, 1, 2, 3]);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_switch_stack() {
+		let mut vec = crate::switch::SwitchVec::<4, _>::from_vec(std::vec![1, 2, 3, 4, 5]);
+
+		assert!(!vec.switch_stack());
+		assert!(vec.is_heap());
+		assert_eq!(vec, [1, 2, 3, 4, 5]);
+
+		assert!(vec.switch_stack_truncate());
+		assert!(!vec.is_heap());
+		assert_eq!(vec, [1, 2, 3, 4]);
+
+		assert!(vec.switch_heap());
+		assert!(vec.switch_stack());
+		assert!(!vec.is_heap());
+		assert_eq!(vec, [1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn test_insert() {
+		let mut vecs = both_backings();
+
+		for vec in &mut vecs {
+			let heap = vec.is_heap();
+
+			assert_eq!(vec.insert(1, 1), Err(1));
+			assert_eq!(vec.insert(0, 2), Ok(()));
+			assert_eq!(vec.insert(1, 3), Ok(()));
+			assert_eq!(vec.insert(0, 4), Ok(()));
+			assert_eq!(vec.insert(4, 5), Err(5));
+			assert_eq!(vec.insert(usize::MAX, 6), Err(6));
+
+			assert_eq!(*vec, [4, 2, 3]);
+			assert_eq!(vec.is_heap(), heap);
+		}
+	}
+
+	#[test]
+	fn test_extend_from_slice() {
+		let mut vec = crate::switch::SwitchVec::<4, i32>::new();
+
+		assert_eq!(vec.extend_from_slice(&[1, 2]), Ok(()));
+		assert_eq!(vec.extend_from_slice(&[3, 4]), Ok(()));
+		assert!(!vec.is_heap());
+
+		#[cfg(feature = "std")]
+		{
+			assert_eq!(vec.extend_from_slice(&[5, 6, 7, 8, 9, 10]), Ok(()));
+			assert!(vec.is_heap());
+			// the heap was sized for everything in one go
+			assert!(vec.capacity() >= 10);
+			assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+		}
+
+		#[cfg(not(feature = "std"))]
+		{
+			assert_eq!(vec.extend_from_slice(&[5]), Err(()));
+			assert_eq!(vec, [1, 2, 3, 4]);
+		}
+	}
+
+	#[test]
+	fn test_debug() {
+		let mut vec = crate::switch::SwitchVec::<2, _>::new();
+		vec.extend([1, 2]);
+
+		assert_eq!(std::format!("{vec:?}"), "[1, 2]");
+		assert!(std::format!("{vec:#?}").starts_with("SwitchVec::Stack"));
+
+		#[cfg(feature = "std")]
+		{
+			vec.push(3).unwrap();
+
+			assert_eq!(std::format!("{vec:?}"), "[1, 2, 3]");
+			assert!(std::format!("{vec:#?}").starts_with("SwitchVec::Heap"));
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_spill_policy() {
+		use crate::switch::{SwitchVec, SpillPolicy};
+
+		let mut vec = SwitchVec::<4, _>::new_with_spill_policy(SpillPolicy::Lazy);
+		let mut progression = std::vec::Vec::new();
+		for i in 0..6 {
+			vec.push(i).unwrap();
+			progression.push(vec.is_heap());
+		}
+		assert_eq!(progression, [false, false, false, false, true, true]);
+
+		let mut vec = SwitchVec::<4, _>::new_with_spill_policy(SpillPolicy::Eager);
+		assert!(!vec.is_heap());
+		let mut progression = std::vec::Vec::new();
+		for i in 0..6 {
+			vec.push(i).unwrap();
+			progression.push(vec.is_heap());
+		}
+		assert_eq!(progression, [true; 6]);
+		assert_eq!(vec, [0, 1, 2, 3, 4, 5]);
+
+		assert_eq!(vec.clone().spill_policy(), SpillPolicy::Eager);
+
+		let mut vec = SwitchVec::<8, _>::new_with_spill_policy(SpillPolicy::At(3));
+		let mut progression = std::vec::Vec::new();
+		for i in 0..6 {
+			vec.push(i).unwrap();
+			progression.push(vec.is_heap());
+		}
+		assert_eq!(progression, [false, false, false, true, true, true]);
+
+		// a threshold past the capacity is the same as lazy
+		let mut vec = SwitchVec::<4, _>::new_with_spill_policy(SpillPolicy::At(100));
+		let mut progression = std::vec::Vec::new();
+		for i in 0..6 {
+			vec.push(i).unwrap();
+			progression.push(vec.is_heap());
+		}
+		assert_eq!(progression, [false, false, false, false, true, true]);
+
+		// changing the policy doesn't move anything until the next reserve
+		let mut vec = SwitchVec::<8, _>::new();
+		vec.extend([1, 2, 3, 4]);
+		vec.set_spill_policy(SpillPolicy::At(2));
+		assert!(!vec.is_heap());
+		vec.push(5).unwrap();
+		assert!(vec.is_heap());
+		assert_eq!(vec, [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_io_write() {
+		use std::io::Write;
+
+		let mut vec = crate::switch::SwitchVec::<4, u8>::new();
+		vec.write_all(b"nya").unwrap();
+		assert!(!vec.is_heap());
+
+		assert_eq!(vec.write(b"~meow").unwrap(), 5);
+		write!(vec, "{}", 42).unwrap();
+		vec.flush().unwrap();
+		assert!(vec.is_heap());
+		assert_eq!(vec, *b"nya~meow42");
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_io_read() {
+		use std::io::{BufRead, Read};
+
+		let mut vecs = both_backings::<u8>();
+
+		for vec in &mut vecs {
+			vec.extend_from_slice(b"nya\nmew~").unwrap();
+
+			let mut buf = [0; 2];
+			assert_eq!(vec.read(&mut buf).unwrap(), 2);
+			assert_eq!(buf, *b"ny");
+			assert_eq!(*vec, *b"a\nmew~");
+
+			let mut line = std::string::String::new();
+			vec.read_line(&mut line).unwrap();
+			assert_eq!(line, "a\n");
+			assert_eq!(vec.fill_buf().unwrap(), b"mew~");
+
+			let mut rest = std::vec::Vec::new();
+			vec.read_to_end(&mut rest).unwrap();
+			assert_eq!(rest, b"mew~");
+			assert!(vec.is_empty());
+			assert_eq!(vec.read(&mut buf).unwrap(), 0);
+		}
+
+		assert!(!vecs[0].is_heap());
+		assert!(vecs[1].is_heap());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn test_eq_vec() {
+		let mut vec = crate::switch::SwitchVec::from_array(crate::array![1, 2, 3 => 4]);
+		assert_eq!(vec, std::vec![1, 2, 3]);
+		assert_eq!(std::vec![1, 2, 3], vec);
+
+		assert!(vec.switch_heap());
+		assert_eq!(vec, std::vec![1, 2, 3]);
+		assert_ne!(std::vec![1, 2], vec);
 	}
 }